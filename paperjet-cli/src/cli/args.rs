@@ -2,9 +2,12 @@ use clap::builder::Styles;
 use clap::builder::styling::AnsiColor;
 use clap::{ArgAction, Parser, Subcommand, value_parser};
 use paperjet::options::{
-	ColorMode, Finishing, MediaSize, MediaSource, MediaType, Orientation, Quality, SidesMode,
+	Collation, ColorMode, Finishing, JobPassword, JobRetention, JobSheets, MediaSize, MediaSource,
+	MediaType, NumberUpLayout, OptionPolicy, Orientation, OutputBin, OutputOrder, PageBorder,
+	PrintScaling, Quality, RenderingIntent, Resolution, SidesMode,
 };
 use pdfium_render::prelude::PdfPageIndex;
+use std::convert::Infallible;
 use std::ffi::c_int;
 use std::path::PathBuf;
 
@@ -57,7 +60,11 @@ pub enum Command {
 	/// paper size, orientation, and others, listed below.
 	/// Support is up to a particular device - unsupported options or option values will be rejected
 	/// and the printing will not commence.
-	Print(PrintArgs),
+	Print(Box<PrintArgs>),
+	/// Moves queued jobs from one printer to another.
+	///
+	/// If no job ID is specified, all queued jobs of the source printer are moved.
+	Move(MoveArgs),
 }
 
 #[derive(clap::Args)]
@@ -69,6 +76,17 @@ pub struct DisplayArgs {
 	pub options: bool,
 }
 
+#[derive(clap::Args)]
+pub struct MoveArgs {
+	/// The ID (as determined by the `list` command) or the name of the printer to move jobs from.
+	pub from: String,
+	/// The ID (as determined by the `list` command) or the name of the printer to move jobs to.
+	pub to: String,
+	/// The ID of the job to move.
+	#[arg(short, long)]
+	pub job: Option<c_int>,
+}
+
 #[derive(Debug, clap::Args)]
 pub struct PrintArgs {
 	/// Paths to the files to be printed.
@@ -109,6 +127,16 @@ pub struct PrintArgs {
 	#[arg(short, long, help_heading = headings::PDF_MANIP)]
 	pub duplex: bool,
 
+	/// Send the files to the printer unchanged, such as ZPL labels or ESC/POS receipts.
+	///
+	/// File transformations and printing options are not applied.
+	#[arg(long, conflicts_with_all = ["from", "to", "duplex", "dry_run"], help_heading = headings::MISC)]
+	pub raw: bool,
+
+	/// Only check whether the printer supports the options, without submitting anything.
+	#[arg(long, help_heading = headings::MISC)]
+	pub dry_run: bool,
+
 	/// Amount of copies [default: 1]
 	///
 	/// In case of multiple files, this option applies to each of them.
@@ -120,6 +148,15 @@ pub struct PrintArgs {
 	)]
 	pub copies: Option<c_int>,
 
+	/// Determines whether copies are output as complete sets, or page by page.
+	#[arg(
+		long,
+		value_name = "MODE",
+		value_parser = possible_values_parser!(Collation),
+		help_heading = headings::PRINT_OPTS
+	)]
+	pub collate: Option<Collation>,
+
 	/// Finishing processes to be performed by the printer.
 	#[arg(
 		long,
@@ -139,10 +176,15 @@ pub struct PrintArgs {
 	pub size: Option<MediaSize>,
 
 	/// Source where the media is pulled from.
+	///
+	/// Besides the values below, numbered trays and rolls (such as `tray-2` or `roll-1`), as well
+	/// as any other source supported by the printer, can be specified.
+	///
+	/// [common values: auto, main, alternate, large-capacity, manual, envelope, by-pass-tray]
 	#[arg(
 		short = 'r',
 		long,
-		value_parser = possible_values_parser!(MediaSource),
+		value_parser = |s: &str| s.parse::<MediaSource>(),
 		help_heading = headings::PRINT_OPTS
 	)]
 	pub source: Option<MediaSource>,
@@ -166,6 +208,24 @@ pub struct PrintArgs {
 	)]
 	pub number_up: Option<c_int>,
 
+	/// Order of document pages on a media side, if printing several pages per side.
+	#[arg(
+		long,
+		value_name = "LAYOUT",
+		value_parser = possible_values_parser!(NumberUpLayout),
+		help_heading = headings::PRINT_OPTS
+	)]
+	pub number_up_layout: Option<NumberUpLayout>,
+
+	/// Border around each document page, if printing several pages per side.
+	#[arg(
+		long,
+		value_name = "BORDER",
+		value_parser = possible_values_parser!(PageBorder),
+		help_heading = headings::PRINT_OPTS
+	)]
+	pub page_border: Option<PageBorder>,
+
 	/// Orientation of document pages.
 	#[arg(
 		short,
@@ -175,6 +235,27 @@ pub struct PrintArgs {
 	)]
 	pub orientation: Option<Orientation>,
 
+	/// Print edge to edge, without margins (if the printer supports it for the media).
+	#[arg(long, help_heading = headings::PRINT_OPTS)]
+	pub borderless: bool,
+
+	/// Determines how document pages are scaled to the media (`none` prints at 1:1).
+	#[arg(
+		long,
+		value_name = "MODE",
+		value_parser = possible_values_parser!(PrintScaling),
+		help_heading = headings::PRINT_OPTS
+	)]
+	pub scaling: Option<PrintScaling>,
+
+	/// Scale document pages to fit the media.
+	#[arg(long, help_heading = headings::PRINT_OPTS)]
+	pub fit_to_page: bool,
+
+	/// Mirror document pages horizontally.
+	#[arg(long, help_heading = headings::PRINT_OPTS)]
+	pub mirror: bool,
+
 	/// Determines whether the printer should use color or monochrome ink.
 	#[arg(
 		short = 'm',
@@ -194,6 +275,24 @@ pub struct PrintArgs {
 	)]
 	pub quality: Option<Quality>,
 
+	/// Resolution of the output, such as 600dpi or 1200x600dpi (cross-feed x feed).
+	#[arg(
+		long,
+		value_name = "RESOLUTION",
+		value_parser = |s: &str| s.parse::<Resolution>(),
+		help_heading = headings::PRINT_OPTS
+	)]
+	pub resolution: Option<Resolution>,
+
+	/// Determines how colors are mapped to the colors the printer can reproduce.
+	#[arg(
+		long,
+		value_name = "INTENT",
+		value_parser = possible_values_parser!(RenderingIntent),
+		help_heading = headings::PRINT_OPTS
+	)]
+	pub rendering_intent: Option<RenderingIntent>,
+
 	/// Determines whether only one or both sides of the media should be printed on.
 	#[arg(
 		short = 'i',
@@ -202,6 +301,105 @@ pub struct PrintArgs {
 		value_parser = possible_values_parser!(SidesMode),
 		help_heading = headings::PRINT_OPTS)]
 	pub sides_mode: Option<SidesMode>,
+
+	/// The order in which pages are output.
+	#[arg(
+		long,
+		value_name = "ORDER",
+		value_parser = possible_values_parser!(OutputOrder),
+		help_heading = headings::PRINT_OPTS
+	)]
+	pub output_order: Option<OutputOrder>,
+
+	/// The output bin (tray) the printed pages are delivered to.
+	///
+	/// Besides the values below, numbered bins (such as `tray-2`, `stacker-1` or `mailbox-3`), as
+	/// well as any other bin supported by the printer, can be specified.
+	///
+	/// [common values: auto, top, middle, bottom, face-up, face-down, large-capacity, my-mailbox]
+	#[arg(
+		long,
+		value_name = "BIN",
+		value_parser = |s: &str| s.parse::<OutputBin>(),
+		help_heading = headings::PRINT_OPTS
+	)]
+	pub output_bin: Option<OutputBin>,
+
+	/// Retains the documents after the job is completed, so that it can be printed again.
+	/// Not supported by CUPS, which retains documents according to its `PreserveJobFiles` setting
+	#[arg(
+		long,
+		value_name = "UNTIL",
+		value_parser = possible_values_parser!(JobRetention),
+		help_heading = headings::PRINT_OPTS
+	)]
+	pub retain: Option<JobRetention>,
+
+	/// Priority of the job, from 1 (lowest) to 100 (highest) [default: 50]
+	#[arg(
+		long,
+		value_parser = value_parser!(c_int).range(1..=100),
+		help_heading = headings::PRINT_OPTS
+	)]
+	pub priority: Option<c_int>,
+
+	/// Banner pages printed at the start and, optionally, at the end of the job.
+	///
+	/// [possible values: none, standard, classified, confidential, secret, topsecret, unclassified]
+	#[arg(
+		long,
+		value_name = "START[,END]",
+		value_parser = |s: &str| s.parse::<JobSheets>(),
+		help_heading = headings::PRINT_OPTS
+	)]
+	pub job_sheets: Option<JobSheets>,
+
+	/// ID of the account the job is charged to.
+	#[arg(long, value_name = "ID", help_heading = headings::PRINT_OPTS)]
+	pub account_id: Option<String>,
+
+	/// ID of the user the job is accounted to.
+	#[arg(long, value_name = "ID", help_heading = headings::PRINT_OPTS)]
+	pub accounting_user_id: Option<String>,
+
+	/// Compress the files with gzip while uploading them (if the printer supports it).
+	#[arg(long, help_heading = headings::PRINT_OPTS)]
+	pub compress: bool,
+
+	/// PIN that has to be entered at the printer to release the job.
+	#[arg(
+		long,
+		value_parser = |s: &str| Ok::<_, Infallible>(JobPassword::new(s)),
+		help_heading = headings::PRINT_OPTS
+	)]
+	pub pin: Option<JobPassword>,
+
+	/// Name of the user the job is submitted as, instead of the current user.
+	#[arg(long, value_name = "NAME", help_heading = headings::PRINT_OPTS)]
+	pub user: Option<String>,
+
+	/// Additional option to pass to the printer, such as a vendor-specific option.
+	///
+	/// Can be specified several times. Options that the printer does not advertise are passed
+	/// without validation.
+	#[arg(
+		long = "option",
+		value_name = "NAME=VALUE",
+		value_parser = parse_name_value,
+		help_heading = headings::PRINT_OPTS
+	)]
+	pub extra_options: Vec<(String, String)>,
+
+	/// How options that the printer does not support are handled [default: strict]
+	///
+	/// `strict` rejects the job, `drop-unsupported` removes such options, and `nearest-supported`
+	/// replaces them with the closest supported value (or removes them, if there is none).
+	#[arg(
+		long,
+		value_parser = possible_values_parser!(OptionPolicy),
+		help_heading = headings::PRINT_OPTS
+	)]
+	pub policy: Option<OptionPolicy>,
 }
 
 impl Args {
@@ -210,6 +408,14 @@ impl Args {
 	}
 }
 
+/// Parses an option of the form `NAME=VALUE`.
+fn parse_name_value(string: &str) -> Result<(String, String), String> {
+	match string.split_once('=') {
+		Some((name, value)) if !name.is_empty() => Ok((name.to_owned(), value.to_owned())),
+		_ => Err(format!("expected NAME=VALUE, got '{string}'")),
+	}
+}
+
 fn help_style() -> Styles {
 	Styles::styled()
 		.context(AnsiColor::White.on_default().dimmed())
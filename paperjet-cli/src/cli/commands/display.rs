@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use anyhow::{Result, anyhow};
+use anyhow::Result;
 use colored::Colorize;
 use paperjet::Printer;
 
@@ -11,7 +11,7 @@ type KeyValueMap = HashMap<String, Option<String>>;
 
 /// The `display` command.
 pub fn display(args: DisplayArgs) -> Result<()> {
-	let printer = get_printer_by_criteria(&args.criteria)?;
+	let printer = common::get_printer_by_criteria(&args.criteria)?;
 	println!("{}\n", printer.get_human_name().bold());
 
 	let info = collect_information(&printer);
@@ -26,47 +26,33 @@ pub fn display(args: DisplayArgs) -> Result<()> {
 	Ok(())
 }
 
-/// Retrieves the printer by specified criteria, which is either the numerical ID or a name.
-fn get_printer_by_criteria(criteria: &str) -> Result<Printer> {
-	let mut printer = None;
-	if let Ok(id) = criteria.parse::<usize>() {
-		printer = common::get_printer_by_id(id);
-	};
-	if printer.is_none() {
-		printer = common::get_printer_by_name(criteria);
-	}
-	printer.ok_or_else(|| {
-		anyhow!(
-			"could not find a printer by criteria: '{}'",
-			criteria.yellow()
-		)
-	})
-}
-
 /// Collects basic printer information into a map.
 fn collect_information(printer: &Printer) -> KeyValueMap {
 	let mut map = HashMap::new();
 
 	map.insert("Identifier".to_owned(), Some(printer.name.to_owned()));
 
+	map.insert("Model".to_owned(), printer.get_make_and_model().cloned());
+
+	map.insert("Location".to_owned(), printer.get_location().cloned());
+
 	map.insert(
-		"Model".to_owned(),
-		printer
-			.get_option("printer-make-and-model")
-			.map(|value| value.to_owned()),
+		"State".to_owned(),
+		printer.get_state().map(|state| state.to_string()),
 	);
 
+	let reasons = printer
+		.get_state_reasons()
+		.iter()
+		.map(|reason| format!("{} ({})", reason.keyword, reason.severity))
+		.collect::<Vec<_>>();
 	map.insert(
-		"State".to_owned(),
-		printer
-			.get_option("printer-state")
-			.and_then(|value| match value.as_str() {
-				"3" => Some("idle"),
-				"4" => Some("printing"),
-				"5" => Some("stopped"),
-				_ => None,
-			})
-			.map(|value| value.to_owned()),
+		"State reasons".to_owned(),
+		Some(if reasons.is_empty() {
+			"none".to_owned()
+		} else {
+			reasons.join(", ")
+		}),
 	);
 
 	map.insert(
@@ -76,9 +62,16 @@ fn collect_information(printer: &Printer) -> KeyValueMap {
 			.map(|value| value.to_owned()),
 	);
 
+	map.insert("Supplies".to_owned(), format_supplies(printer));
+
 	map.insert(
-		"Ink level".to_owned(),
-		get_marker_level(printer).map(|percentage| percentage.to_string() + "%"),
+		"PIN release".to_owned(),
+		paperjet::get_capabilities(printer)
+			.ok()
+			.map(|capabilities| match capabilities.supports_job_password() {
+				true => "supported".to_owned(),
+				false => "not supported".to_owned(),
+			}),
 	);
 
 	map
@@ -107,21 +100,26 @@ fn print_key_value_pairs(map: &KeyValueMap) {
 	}
 }
 
-/// Calculates the percentage of the marker level in a printer.
-fn get_marker_level(printer: &Printer) -> Option<u8> {
-	let marker_levels = printer.get_option("marker-levels")?.parse::<u32>().ok()?;
-
-	let marker_low_levels = printer
-		.get_option("marker-low-levels")?
-		.parse::<u32>()
-		.unwrap_or(0);
-	let marker_high_levels = printer
-		.get_option("marker-high-levels")?
-		.parse::<u32>()
-		.unwrap_or(100);
-
-	let percentage = ((marker_levels - marker_low_levels) as f64)
-		/ ((marker_high_levels - marker_low_levels) as f64)
-		* 100.0;
-	Some(percentage as u8)
+/// Formats the supplies of the printer and their levels into a single line.
+fn format_supplies(printer: &Printer) -> Option<String> {
+	let supplies = printer.get_supplies();
+	if supplies.is_empty() {
+		return None;
+	}
+	let line = supplies
+		.iter()
+		.map(|supply| {
+			let level = supply
+				.get_percentage()
+				.map(|percentage| percentage.to_string() + "%")
+				.unwrap_or_else(|| "unknown".to_owned());
+			if supply.is_low() {
+				format!("{} {} ({})", supply.name, level.yellow(), "low".yellow())
+			} else {
+				format!("{} {}", supply.name, level)
+			}
+		})
+		.collect::<Vec<_>>()
+		.join(", ");
+	Some(line)
 }
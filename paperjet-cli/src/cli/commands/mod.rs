@@ -1,7 +1,9 @@
 mod display;
 mod list;
+mod move_jobs;
 mod print;
 
 pub use display::*;
 pub use list::*;
+pub use move_jobs::*;
 pub use print::*;
@@ -0,0 +1,26 @@
+use anyhow::Result;
+
+use crate::cli::args::MoveArgs;
+use crate::cli::common;
+
+/// The `move` command.
+pub fn move_jobs(args: MoveArgs) -> Result<()> {
+	let from = common::get_printer_by_criteria(&args.from)?;
+	let to = common::get_printer_by_criteria(&args.to)?;
+
+	match args.job {
+		Some(job_id) => {
+			paperjet::move_job(job_id, &from, &to)?;
+			println!("Job {job_id} has been moved to {}.", to.get_human_name());
+		}
+		None => {
+			paperjet::move_all_jobs(&from, &to)?;
+			println!(
+				"All jobs of {} have been moved to {}.",
+				from.get_human_name(),
+				to.get_human_name()
+			);
+		}
+	}
+	Ok(())
+}
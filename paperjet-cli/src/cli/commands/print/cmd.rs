@@ -2,10 +2,11 @@ use std::fs::File;
 use std::io::Cursor;
 use std::path::PathBuf;
 
-use anyhow::{Result, anyhow};
+use anyhow::{Result, anyhow, bail};
 use colored::Colorize;
 use paperjet::Printer;
-use paperjet::options::PrintOptions;
+use paperjet::options::{OptionPolicy, PrintOptions};
+use paperjet::validation::UnsupportedOption;
 
 use crate::cli::args::PrintArgs;
 use crate::cli::commands::print::{duplex, transform};
@@ -16,6 +17,25 @@ pub fn print(args: PrintArgs) -> Result<()> {
 	let files = open_files(&args.paths)?;
 	let printer = select_printer(&args)?;
 
+	// Raw mode: submit the files unchanged, without options
+	if args.raw {
+		let job = paperjet::print_raw(files, printer)?;
+		println!("Files have been submitted for printing (job {}).", job.id);
+		return Ok(());
+	}
+
+	let options = PrintOptions::from(&args);
+
+	// Report all unsupported options at once, before any work is done
+	let is_strict = matches!(options.policy, None | Some(OptionPolicy::Strict));
+	if is_strict || args.dry_run {
+		check_options(&printer, &options)?;
+	}
+	if args.dry_run {
+		println!("All options are supported by the printer.");
+		return Ok(());
+	}
+
 	// Transform
 	let documents = transform::transform(files, &args)?
 		.into_iter()
@@ -28,9 +48,9 @@ pub fn print(args: PrintArgs) -> Result<()> {
 	}
 
 	// Simplex mode: submit to print
-	let options = PrintOptions::from(&args);
-	paperjet::print(documents, printer, options)?;
-	println!("Files have been submitted for printing.");
+	let job = paperjet::print(documents, printer, options)?;
+	common::print_substitutions(&job.substitutions);
+	println!("Files have been submitted for printing (job {}).", job.id);
 
 	Ok(())
 }
@@ -53,6 +73,40 @@ fn open_files(paths: &[PathBuf]) -> Result<Vec<File>> {
 		.collect::<Result<_>>()
 }
 
+/// Checks all `options` with the printer.
+/// Returns `Ok` if all options are supported, or `Err` listing every unsupported option otherwise.
+fn check_options(printer: &Printer, options: &PrintOptions) -> Result<()> {
+	let report = paperjet::validate(printer, options)?;
+	if report.is_valid() {
+		return Ok(());
+	}
+	let lines = report
+		.unsupported
+		.iter()
+		.map(format_unsupported_option)
+		.collect::<Vec<_>>()
+		.join("\n");
+	bail!("printer does not support the following options:\n{lines}")
+}
+
+/// Formats an unsupported option as a line of a list.
+fn format_unsupported_option(option: &UnsupportedOption) -> String {
+	let line = format!("  - {} = {}", option.name, option.value.yellow());
+	if let Some(ref reason) = option.reason {
+		return format!("{line} ({reason})");
+	}
+	if option.supported.is_empty() {
+		return line;
+	}
+	let supported = option
+		.supported
+		.iter()
+		.map(|value| value.green().to_string())
+		.collect::<Vec<_>>()
+		.join(", ");
+	format!("{line} (supported: {supported})")
+}
+
 /// Selects a printer according to the arguments.
 fn select_printer(args: &PrintArgs) -> Result<Printer> {
 	if let Some(id) = args.printer_id {
@@ -7,7 +7,7 @@ use paperjet::options::PrintOptions;
 use pdfium_render::prelude::*;
 
 use crate::cli::args::PrintArgs;
-use crate::cli::pdf;
+use crate::cli::{common, pdf};
 
 /// Starts the interactive duplex printing mode.
 ///
@@ -38,7 +38,8 @@ where
 
 	// Start interactions
 	println!("\nPrinting the front side...");
-	paperjet::print([front], printer.clone(), options.clone())?;
+	let job = paperjet::print([front], printer.clone(), options.clone())?;
+	common::print_substitutions(&job.substitutions);
 	println!("The front side has been submitted.");
 
 	print!(
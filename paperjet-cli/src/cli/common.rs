@@ -1,7 +1,13 @@
 use std::cmp::Ordering;
 
+use anyhow::{Result, anyhow};
+use colored::Colorize;
 use paperjet::Printer;
-use paperjet::options::{CopiesInt, NumberUpInt, PrintOptions};
+use paperjet::options::{
+	Compression, CopiesInt, FitToPageBool, JobAccountId, JobAccountingUserId, JobPriorityInt,
+	MirrorBool, NumberUpInt, PrintOptions,
+};
+use paperjet::validation::OptionSubstitution;
 
 use crate::cli::args::PrintArgs;
 use crate::cli::snapshot;
@@ -18,6 +24,23 @@ pub fn get_sorted_printers() -> Vec<Printer> {
 	printers
 }
 
+/// Retrieves the printer by specified criteria, which is either the numerical ID or a name.
+pub fn get_printer_by_criteria(criteria: &str) -> Result<Printer> {
+	let mut printer = None;
+	if let Ok(id) = criteria.parse::<usize>() {
+		printer = get_printer_by_id(id);
+	};
+	if printer.is_none() {
+		printer = get_printer_by_name(criteria);
+	}
+	printer.ok_or_else(|| {
+		anyhow!(
+			"could not find a printer by criteria: '{}'",
+			criteria.yellow()
+		)
+	})
+}
+
 /// Retrieves the printer corresponding to the specified `id`.
 /// The ID refers to the position in the list output by the `list` command.
 pub fn get_printer_by_id(id: usize) -> Option<Printer> {
@@ -70,19 +93,62 @@ fn get_printer_by_name_from_api(name: &str) -> Option<Printer> {
 	})
 }
 
+/// Prints the changes that were made to unsupported options, if any.
+pub fn print_substitutions(substitutions: &[OptionSubstitution]) {
+	for substitution in substitutions {
+		let change = match substitution.substitute {
+			Some(ref value) => format!("replaced with '{}'", value.green()),
+			None => "removed".to_owned(),
+		};
+		println!(
+			"{} option {} = '{}' is not supported and was {}",
+			"warning:".bold().yellow(),
+			substitution.name,
+			substitution.requested.yellow(),
+			change
+		);
+	}
+}
+
 impl From<&PrintArgs> for PrintOptions {
 	fn from(value: &PrintArgs) -> PrintOptions {
 		PrintOptions {
 			copies: value.copies.map(CopiesInt::from),
+			collate: value.collate,
 			finishings: value.finishings.clone(),
+			finishing_templates: None,
 			media_size: value.size,
-			media_source: value.source,
+			media_source: value.source.clone(),
 			media_type: value.media_type,
 			number_up: value.number_up.map(NumberUpInt::from),
+			number_up_layout: value.number_up_layout,
+			page_border: value.page_border,
 			orientation: value.orientation,
+			borderless: value.borderless.then_some(true),
+			page_ranges: None,
+			print_scaling: value.scaling,
+			fit_to_page: value.fit_to_page.then_some(FitToPageBool(true)),
+			mirror: value.mirror.then_some(MirrorBool(true)),
 			color_mode: value.color_mode,
 			quality: value.quality,
+			printer_resolution: value.resolution,
+			print_rendering_intent: value.rendering_intent,
 			sides_mode: value.sides_mode,
+			output_order: value.output_order,
+			output_bin: value.output_bin.clone(),
+			retention: value.retain,
+			job_priority: value.priority.map(JobPriorityInt::from),
+			job_sheets: value.job_sheets,
+			job_account_id: value.account_id.as_deref().map(JobAccountId::from),
+			job_accounting_user_id: value
+				.accounting_user_id
+				.as_deref()
+				.map(JobAccountingUserId::from),
+			compression: value.compress.then_some(Compression::Gzip),
+			job_password: value.pin.clone(),
+			requesting_user_name: value.user.clone(),
+			extra: value.extra_options.iter().cloned().collect(),
+			policy: value.policy,
 		}
 	}
 }
@@ -13,6 +13,7 @@ pub fn run_command(args: Args) -> Result<()> {
 	match args.command {
 		Command::List => commands::list(),
 		Command::Display(d_args) => commands::display(d_args),
-		Command::Print(p_args) => commands::print(p_args),
+		Command::Print(p_args) => commands::print(*p_args),
+		Command::Move(m_args) => commands::move_jobs(m_args),
 	}
 }
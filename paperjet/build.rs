@@ -13,20 +13,48 @@ fn main() {
 
 const CUPS_ALLOWED_FUNCTIONS: &[&str] = &[
 	"cupsAddOption",
-	"cupsCancelDestJob",
 	"cupsCheckDestSupported",
-	"cupsCloseDestJob",
+	"cupsCopyDestConflicts",
 	"cupsCopyDestInfo",
-	"cupsCreateDestJob",
-	"cupsFinishDestDocument",
+	"cupsDoRequest",
+	"cupsEncodeOptions2",
+	"cupsFindDestSupported",
 	"cupsFreeDestInfo",
 	"cupsFreeDests",
 	"cupsFreeOptions",
+	"cupsGetDestMediaByName",
+	"cupsGetDestMediaDefault",
 	"cupsGetDests2",
 	"cupsGetNamedDest",
+	"cupsGetResponse",
 	"cupsLastErrorString",
-	"cupsStartDestDocument",
+	"cupsParseOptions",
+	"cupsRemoveOption",
+	"cupsSendRequest",
+	"cupsServer",
+	"cupsUser",
 	"cupsWriteRequestData",
+	"ippAddBoolean",
+	"ippAddInteger",
+	"ippAddString",
+	"ippAddStrings",
+	"ippDelete",
+	"ippFindAttribute",
+	"ippFirstAttribute",
+	"ippGetBoolean",
+	"ippGetCollection",
+	"ippGetCount",
+	"ippGetGroupTag",
+	"ippGetInteger",
+	"ippGetName",
+	"ippGetRange",
+	"ippGetResolution",
+	"ippGetStatusCode",
+	"ippGetString",
+	"ippGetValueTag",
+	"ippNewRequest",
+	"ippNextAttribute",
+	"ippPort",
 ];
 fn cups_bindings() {
 	let mut builder = bindgen::builder().header("headers/cups.h");
@@ -37,7 +65,10 @@ fn cups_bindings() {
 	}
 	// Type config:
 	builder = builder
+		.newtype_enum("ipp_op_e")
 		.newtype_enum("ipp_status_e")
+		.newtype_enum("ipp_tag_e")
+		.newtype_enum("ipp_res_e")
 		.newtype_enum("http_status_e");
 
 	// Generate & write:
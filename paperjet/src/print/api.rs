@@ -1,8 +1,11 @@
 use std::collections::HashMap;
 use std::io::Read;
 
+use crate::capabilities::Capabilities;
 use crate::error::PrintError;
-use crate::options::PrintOptions;
+use crate::events::{Event, EventKind, Subscription};
+use crate::options::{DocumentOptions, PrintOptions};
+use crate::validation::{ConflictResolution, OptionSubstitution, ValidationReport};
 
 // MARK: - Public API Methods
 
@@ -21,7 +24,14 @@ pub fn get_default_printer() -> Option<Printer> {
 }
 
 /// Prints the contents of each of the specified [`readers`].
-pub fn print<I, R>(readers: I, printer: Printer, options: PrintOptions) -> Result<(), PrintError>
+///
+/// Unsupported options are handled according to [`PrintOptions::policy`]; any changes made to the
+/// options are listed in the returned [`SubmittedJob`].
+pub fn print<I, R>(
+	readers: I,
+	printer: Printer,
+	options: PrintOptions,
+) -> Result<SubmittedJob, PrintError>
 where
 	I: IntoIterator<Item = R>,
 	R: Read,
@@ -29,6 +39,100 @@ where
 	PlatformSpecificApi::print(readers, printer, options)
 }
 
+/// Prints each of the specified documents in a single job, applying the document's options on top
+/// of the job's `options`.
+///
+/// Unsupported options (of the job or of a document) are handled according to
+/// [`PrintOptions::policy`]; any changes made to the options are listed in the returned
+/// [`SubmittedJob`]. Documents can only have options that differ from the job's if the printer
+/// supports `multiple-document-handling`; otherwise, this is handled as an unsupported option, and
+/// the job's options are used for all documents.
+pub fn print_documents<I, R>(
+	documents: I,
+	printer: Printer,
+	options: PrintOptions,
+) -> Result<SubmittedJob, PrintError>
+where
+	I: IntoIterator<Item = (R, DocumentOptions)>,
+	R: Read,
+{
+	PlatformSpecificApi::print_documents(documents, printer, options)
+}
+
+/// Prints the contents of each of the specified [`readers`] without any processing, passing the
+/// bytes to the printer unchanged.
+///
+/// This is intended for printers that are controlled with their own command language, such as
+/// label printers (ZPL) or receipt printers (ESC/POS); see [`crate::raw`] for helpers that build
+/// such documents. No print options are sent, since they would not be applied to raw documents.
+pub fn print_raw<I, R>(readers: I, printer: Printer) -> Result<SubmittedJob, PrintError>
+where
+	I: IntoIterator<Item = R>,
+	R: Read,
+{
+	PlatformSpecificApi::print_raw(readers, printer)
+}
+
+/// Checks the specified options for combinations that the printer does not support, even though
+/// each option might be supported on its own (for example, duplex printing on transparencies).
+///
+/// Returns the options adjusted to resolve any conflicts, or the conflicts themselves if they
+/// could not be resolved automatically.
+pub fn resolve_conflicts(
+	printer: &Printer,
+	options: &PrintOptions,
+) -> Result<ConflictResolution, PrintError> {
+	PlatformSpecificApi::resolve_conflicts(printer, options)
+}
+
+/// Checks each of the specified options with the printer, without creating a print job.
+///
+/// Unlike [`print()`], which fails on the first unsupported option, this function reports all
+/// unsupported options, along with the values the printer supports for each of them. Values that
+/// are invalid regardless of the printer (such as a reversed page range) are reported as well.
+pub fn validate(printer: &Printer, options: &PrintOptions) -> Result<ValidationReport, PrintError> {
+	PlatformSpecificApi::validate(printer, options)
+}
+
+/// Retrieves the capabilities of the printer that can't be checked with [`validate()`], such as
+/// support for job passwords.
+pub fn get_capabilities(printer: &Printer) -> Result<Capabilities, PrintError> {
+	PlatformSpecificApi::get_capabilities(printer)
+}
+
+/// Moves the job with the specified ID from the printer `from` to the printer `to`.
+/// The job must not have been completed yet.
+pub fn move_job(job_id: i32, from: &Printer, to: &Printer) -> Result<(), PrintError> {
+	PlatformSpecificApi::move_job(job_id, from, to)
+}
+
+/// Moves all jobs that have not been completed yet from the printer `from` to the printer `to`.
+pub fn move_all_jobs(from: &Printer, to: &Printer) -> Result<(), PrintError> {
+	PlatformSpecificApi::move_all_jobs(from, to)
+}
+
+/// Restarts the job with the specified ID, printing its documents again.
+///
+/// Completed jobs can only be restarted while their documents are retained. With CUPS, this is
+/// determined by the server's `PreserveJobFiles` setting; other servers may support
+/// [`PrintOptions::retention`].
+pub fn restart_job(job_id: i32) -> Result<(), PrintError> {
+	PlatformSpecificApi::restart_job(job_id)
+}
+
+/// Subscribes to events of the specified kinds.
+///
+/// If `printer` is `Some`, only events of that printer (and its jobs) are reported; otherwise,
+/// events of all printers are reported. If `events` is empty, all kinds of events are reported.
+/// The returned [`Subscription`] is cancelled when dropped.
+pub fn subscribe(
+	printer: Option<&Printer>,
+	events: &[EventKind],
+) -> Result<Subscription, PrintError> {
+	let id = PlatformSpecificApi::create_subscription(printer, events)?;
+	Ok(Subscription::new(id))
+}
+
 // MARK: - Public API trait
 
 /// A unit struct representing the current platform.
@@ -47,10 +151,70 @@ pub trait CrossPlatformApi {
 	fn get_default_printer() -> Option<Printer>;
 
 	/// See [`crate::print::print()`].
-	fn print<I, R>(readers: I, printer: Printer, options: PrintOptions) -> Result<(), PrintError>
+	fn print<I, R>(
+		readers: I,
+		printer: Printer,
+		options: PrintOptions,
+	) -> Result<SubmittedJob, PrintError>
+	where
+		I: IntoIterator<Item = R>,
+		R: Read;
+
+	/// See [`crate::print::print_documents()`].
+	fn print_documents<I, R>(
+		documents: I,
+		printer: Printer,
+		options: PrintOptions,
+	) -> Result<SubmittedJob, PrintError>
+	where
+		I: IntoIterator<Item = (R, DocumentOptions)>,
+		R: Read;
+
+	/// See [`crate::print::print_raw()`].
+	fn print_raw<I, R>(readers: I, printer: Printer) -> Result<SubmittedJob, PrintError>
 	where
 		I: IntoIterator<Item = R>,
 		R: Read;
+
+	/// See [`crate::print::resolve_conflicts()`].
+	fn resolve_conflicts(
+		printer: &Printer,
+		options: &PrintOptions,
+	) -> Result<ConflictResolution, PrintError>;
+
+	/// See [`crate::print::validate()`].
+	fn validate(printer: &Printer, options: &PrintOptions) -> Result<ValidationReport, PrintError>;
+
+	/// See [`crate::print::get_capabilities()`].
+	fn get_capabilities(printer: &Printer) -> Result<Capabilities, PrintError>;
+
+	/// See [`PrintOptions::from_cups_options()`].
+	fn parse_cups_options(options: &str) -> Result<PrintOptions, PrintError>;
+
+	/// See [`PrintOptions::to_cups_options()`].
+	fn format_cups_options(options: &PrintOptions) -> String;
+
+	/// See [`crate::print::move_job()`].
+	fn move_job(job_id: i32, from: &Printer, to: &Printer) -> Result<(), PrintError>;
+
+	/// See [`crate::print::move_all_jobs()`].
+	fn move_all_jobs(from: &Printer, to: &Printer) -> Result<(), PrintError>;
+
+	/// See [`crate::print::restart_job()`].
+	fn restart_job(job_id: i32) -> Result<(), PrintError>;
+
+	/// Creates a subscription, and returns its ID. See [`crate::print::subscribe()`].
+	fn create_subscription(
+		printer: Option<&Printer>,
+		events: &[EventKind],
+	) -> Result<i32, PrintError>;
+
+	/// Returns the events of the subscription with the specified ID, starting at the event with
+	/// the sequence number `first`.
+	fn get_notifications(subscription: i32, first: i32) -> Result<Vec<Event>, PrintError>;
+
+	/// Cancels the subscription with the specified ID.
+	fn cancel_subscription(subscription: i32) -> Result<(), PrintError>;
 }
 
 // MARK: - Structs
@@ -75,3 +239,12 @@ impl Printer {
 		self.get_option("printer-info").unwrap_or(&self.name)
 	}
 }
+
+/// A struct representing a job that was submitted for printing.
+#[derive(Clone, Debug)]
+pub struct SubmittedJob {
+	/// The ID of the job, assigned by the backend.
+	pub id: i32,
+	/// The changes that were made to unsupported options before submitting the job.
+	pub substitutions: Vec<OptionSubstitution>,
+}
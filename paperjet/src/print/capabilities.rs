@@ -0,0 +1,20 @@
+/// Features of a printer that can't be checked by validating a single option value.
+#[derive(Clone, Debug, Default)]
+pub struct Capabilities {
+	/// The maximum length of a job password (PIN), or `None` if the printer does not support
+	/// job passwords.
+	pub job_password_max_length: Option<usize>,
+	/// The methods the printer supports for encrypting job passwords, such as `none` or `sha2-256`.
+	pub job_password_encryptions: Vec<String>,
+}
+impl Capabilities {
+	/// Returns `true` if the printer supports holding jobs until a password (PIN) is entered at
+	/// the device, with [`crate::options::PrintOptions::job_password`], and `false` otherwise.
+	pub fn supports_job_password(&self) -> bool {
+		self.job_password_max_length.is_some()
+			&& self
+				.job_password_encryptions
+				.iter()
+				.any(|encryption| encryption == "none")
+	}
+}
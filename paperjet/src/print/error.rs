@@ -26,6 +26,14 @@ pub enum PrintError {
 	#[error("printer does not support option: {name} = {value}")]
 	UnsupportedOption { name: String, value: String },
 
+	/// An error that indicates that an option value is not valid, regardless of the printer.
+	#[error("invalid option value: {name} = {value} ({reason})")]
+	InvalidOption {
+		name: String,
+		value: String,
+		reason: String,
+	},
+
 	/// An error reported by the backend API (for example, CUPS on Unix systems).
 	#[error("{0}")]
 	Backend(String),
@@ -0,0 +1,319 @@
+use std::collections::{HashMap, VecDeque};
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+use crate::error::PrintError;
+use crate::print::printer::{PrinterState, StateReason};
+use crate::print::{CrossPlatformApi, PlatformSpecificApi};
+
+/// The interval between two requests for new events, if not set otherwise.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+// MARK: - Subscription
+
+/// A subscription to printer and job events.
+///
+/// Events can be retrieved with [`Subscription::poll()`], or by iterating over the subscription,
+/// which blocks until the next event arrives. The subscription is cancelled when it is dropped.
+#[derive(Debug)]
+pub struct Subscription {
+	/// The subscription ID, assigned by the backend.
+	id: i32,
+	/// The sequence number of the next event that has not been received yet.
+	next_sequence_number: i32,
+	/// Events that have been received, but not yet returned by the iterator.
+	pending: VecDeque<Event>,
+	/// The interval between two requests for new events, when iterating.
+	poll_interval: Duration,
+}
+impl Subscription {
+	/// Creates a subscription with the specified ID, received from the backend.
+	pub(crate) fn new(id: i32) -> Self {
+		Self {
+			id,
+			next_sequence_number: 1,
+			pending: VecDeque::new(),
+			poll_interval: DEFAULT_POLL_INTERVAL,
+		}
+	}
+	/// Returns the ID of this subscription.
+	pub fn get_id(&self) -> i32 {
+		self.id
+	}
+	/// Sets the interval between two requests for new events, used when iterating over this
+	/// subscription.
+	pub fn set_poll_interval(&mut self, interval: Duration) {
+		self.poll_interval = interval;
+	}
+	/// Returns the events that occurred since the last call, without blocking.
+	/// If no new events occurred, returns an empty vector.
+	pub fn poll(&mut self) -> Result<Vec<Event>, PrintError> {
+		let mut events = self.pending.drain(..).collect::<Vec<_>>();
+		events.extend(self.fetch()?);
+		Ok(events)
+	}
+	/// Retrieves events that have not been received yet from the backend.
+	fn fetch(&mut self) -> Result<Vec<Event>, PrintError> {
+		let events = PlatformSpecificApi::get_notifications(self.id, self.next_sequence_number)?;
+		if let Some(last) = events.iter().map(|event| event.sequence_number).max() {
+			self.next_sequence_number = self.next_sequence_number.max(last + 1);
+		}
+		Ok(events)
+	}
+}
+impl Iterator for Subscription {
+	type Item = Result<Event, PrintError>;
+	/// Returns the next event, blocking until one arrives.
+	/// Returns `Some(Err(...))` if events could not be retrieved.
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(event) = self.pending.pop_front() {
+				return Some(Ok(event));
+			}
+			match self.fetch() {
+				Ok(events) if events.is_empty() => thread::sleep(self.poll_interval),
+				Ok(events) => self.pending.extend(events),
+				Err(error) => return Some(Err(error)),
+			}
+		}
+	}
+}
+impl Drop for Subscription {
+	fn drop(&mut self) {
+		// Errors can't be reported here; the backend removes the subscription eventually anyway.
+		let _ = PlatformSpecificApi::cancel_subscription(self.id);
+	}
+}
+
+// MARK: - Events
+
+/// A kind of event that can be subscribed to.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum EventKind {
+	/// The state of a printer changed.
+	PrinterStateChanged,
+	/// A printer was added.
+	PrinterAdded,
+	/// A printer was deleted.
+	PrinterDeleted,
+	/// The configuration of a printer changed.
+	PrinterConfigChanged,
+	/// A printer was stopped.
+	PrinterStopped,
+	/// A printer was restarted.
+	PrinterRestarted,
+	/// A printer was shut down.
+	PrinterShutdown,
+	/// A job was created.
+	JobCreated,
+	/// A job made progress (for example, a page was printed).
+	JobProgress,
+	/// A job was completed, cancelled, or aborted.
+	JobCompleted,
+	/// The state of a job changed.
+	JobStateChanged,
+	/// A job was stopped.
+	JobStopped,
+	/// The attributes of a job changed.
+	JobConfigChanged,
+	/// Any other event, reported by its IPP keyword.
+	#[strum(default)]
+	Other(String),
+}
+
+/// The state of a job.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "kebab-case")]
+pub enum JobState {
+	/// The job is waiting to be processed.
+	Pending,
+	/// The job is held, and will not be processed until it is released.
+	PendingHeld,
+	/// The job is being processed.
+	Processing,
+	/// The job has been stopped while processing.
+	ProcessingStopped,
+	/// The job was cancelled.
+	Canceled,
+	/// The job was aborted due to an error.
+	Aborted,
+	/// The job was completed successfully.
+	Completed,
+}
+impl JobState {
+	/// Maps a value of the IPP `job-state` attribute to a job state.
+	fn from_ipp(value: &str) -> Option<Self> {
+		match value {
+			"3" => Some(Self::Pending),
+			"4" => Some(Self::PendingHeld),
+			"5" => Some(Self::Processing),
+			"6" => Some(Self::ProcessingStopped),
+			"7" => Some(Self::Canceled),
+			"8" => Some(Self::Aborted),
+			"9" => Some(Self::Completed),
+			_ => None,
+		}
+	}
+}
+
+/// An event reported by a [`Subscription`].
+#[derive(Clone, Debug)]
+pub struct Event {
+	/// The kind of this event.
+	pub kind: EventKind,
+	/// The sequence number of this event within its subscription.
+	pub sequence_number: i32,
+	/// The name of the printer this event relates to, if any.
+	pub printer_name: Option<String>,
+	/// The state of the printer at the time of this event, if reported.
+	pub printer_state: Option<PrinterState>,
+	/// The reasons for the state of the printer at the time of this event.
+	pub printer_state_reasons: Vec<StateReason>,
+	/// The ID of the job this event relates to, if any.
+	pub job_id: Option<i32>,
+	/// The state of the job at the time of this event, if reported.
+	pub job_state: Option<JobState>,
+	/// A human-readable description of this event.
+	pub text: Option<String>,
+}
+impl Event {
+	/// Creates an event from the attributes of an IPP event notification, as pairs of attribute
+	/// names and their values. Returns `None` if the notification does not specify its kind.
+	pub(crate) fn from_attributes(attributes: &HashMap<String, Vec<String>>) -> Option<Self> {
+		let first = |name: &str| attributes.get(name).and_then(|values| values.first());
+		let number = |name: &str| first(name).and_then(|value| value.parse::<i32>().ok());
+
+		let kind = EventKind::from_str(first("notify-subscribed-event")?).ok()?;
+		let printer_state_reasons = attributes
+			.get("printer-state-reasons")
+			.into_iter()
+			.flatten()
+			.filter(|reason| *reason != "none")
+			.map(|reason| StateReason::parse(reason))
+			.collect();
+
+		Some(Self {
+			kind,
+			sequence_number: number("notify-sequence-number").unwrap_or_default(),
+			printer_name: first("printer-name").cloned(),
+			printer_state: first("printer-state").and_then(|value| PrinterState::from_ipp(value)),
+			printer_state_reasons,
+			job_id: number("notify-job-id"),
+			job_state: first("job-state").and_then(|value| JobState::from_ipp(value)),
+			text: first("notify-text").cloned(),
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::print::printer::{Severity, StateReasonKeyword};
+
+	fn attributes(pairs: &[(&str, &[&str])]) -> HashMap<String, Vec<String>> {
+		pairs
+			.iter()
+			.map(|(name, values)| {
+				let values = values.iter().map(|value| value.to_string()).collect();
+				(name.to_string(), values)
+			})
+			.collect()
+	}
+
+	#[test]
+	fn if_printer_notification_then_event_has_printer_state() {
+		// A printer was paused with a toner warning
+		let event = Event::from_attributes(&attributes(&[
+			("notify-subscribed-event", &["printer-state-changed"]),
+			("notify-sequence-number", &["4"]),
+			("printer-name", &["office"]),
+			("printer-state", &["5"]),
+			("printer-state-reasons", &["paused", "toner-low-warning"]),
+			("notify-text", &["Printer paused"]),
+		]))
+		.expect("Event could not be parsed");
+
+		assert_eq!(
+			event.kind,
+			EventKind::PrinterStateChanged,
+			"Kind should be parsed from notify-subscribed-event"
+		);
+		assert_eq!(event.sequence_number, 4, "Sequence number should be parsed");
+		assert_eq!(
+			event.printer_name.as_deref(),
+			Some("office"),
+			"Printer name should be parsed"
+		);
+		assert_eq!(
+			event.printer_state,
+			Some(PrinterState::Stopped),
+			"Printer state 5 should be stopped"
+		);
+		assert_eq!(
+			event.printer_state_reasons.len(),
+			2,
+			"Both state reasons should be parsed"
+		);
+		assert_eq!(
+			event.printer_state_reasons[1].keyword,
+			StateReasonKeyword::TonerLow,
+			"Suffix should be stripped from the state reason keyword"
+		);
+		assert_eq!(
+			event.printer_state_reasons[1].severity,
+			Severity::Warning,
+			"Severity should be parsed from the state reason suffix"
+		);
+		assert_eq!(event.job_id, None, "Printer event should have no job");
+		assert_eq!(
+			event.text.as_deref(),
+			Some("Printer paused"),
+			"Text should be parsed from notify-text"
+		);
+	}
+
+	#[test]
+	fn if_job_notification_then_event_has_job_state() {
+		// A job was completed, and the printer has no state reasons
+		let event = Event::from_attributes(&attributes(&[
+			("notify-subscribed-event", &["job-completed"]),
+			("notify-sequence-number", &["12"]),
+			("notify-job-id", &["345"]),
+			("job-state", &["9"]),
+			("printer-state-reasons", &["none"]),
+		]))
+		.expect("Event could not be parsed");
+
+		assert_eq!(
+			event.kind,
+			EventKind::JobCompleted,
+			"Kind should be parsed from notify-subscribed-event"
+		);
+		assert_eq!(
+			event.job_id,
+			Some(345),
+			"Job ID should be parsed from notify-job-id"
+		);
+		assert_eq!(
+			event.job_state,
+			Some(JobState::Completed),
+			"Job state 9 should be completed"
+		);
+		assert!(
+			event.printer_state_reasons.is_empty(),
+			"State reason none should be skipped"
+		);
+	}
+
+	#[test]
+	fn if_notification_has_no_kind_then_no_event() {
+		// The notification has no notify-subscribed-event
+		let event = Event::from_attributes(&attributes(&[("notify-sequence-number", &["1"])]));
+		assert!(
+			event.is_none(),
+			"Notification without a kind should not be parsed"
+		);
+	}
+}
@@ -1,8 +1,13 @@
 mod api;
 pub use api::*;
 
+pub mod capabilities;
 pub mod error;
+pub mod events;
 pub mod options;
+pub mod printer;
+pub mod raw;
+pub mod validation;
 
 mod util;
 
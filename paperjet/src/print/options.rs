@@ -1,12 +1,25 @@
+use std::collections::BTreeMap;
+use std::convert::Infallible;
 use std::ffi::c_int;
+use std::fmt::{self, Display, Formatter};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::error::PrintError;
+use crate::print::{CrossPlatformApi, PlatformSpecificApi};
 
 /// A struct that defines options for a print job.
 #[derive(Clone, Debug, Default)]
 pub struct PrintOptions {
 	/// Amount of copies.
 	pub copies: Option<CopiesInt>,
+	/// Determines whether copies of the documents are collated.
+	pub collate: Option<Collation>,
 	/// Finishing processes such as stapling, folding, etc.
 	pub finishings: Option<Vec<Finishing>>,
+	/// Finishing processes specified by their template names (sent as `finishings-col`).
+	/// Unlike [`Self::finishings`], this also allows vendor-specific templates.
+	pub finishing_templates: Option<Vec<FinishingTemplate>>,
 	/// Media size.
 	pub media_size: Option<MediaSize>,
 	/// The source where the media is pulled from.
@@ -15,20 +28,466 @@ pub struct PrintOptions {
 	pub media_type: Option<MediaType>,
 	/// Number of document pages per media side.
 	pub number_up: Option<NumberUpInt>,
+	/// The order in which document pages are placed on a media side, if [`Self::number_up`] is
+	/// greater than 1.
+	pub number_up_layout: Option<NumberUpLayout>,
+	/// The border drawn around each document page, if [`Self::number_up`] is greater than 1.
+	pub page_border: Option<PageBorder>,
 	/// Orientation of document pages on the media.
 	pub orientation: Option<Orientation>,
+	/// Determines whether the media is printed on edge to edge, without margins.
+	/// Requires the printer to list a borderless variant of the media size (and of the media type,
+	/// if one is set) in its media database.
+	pub borderless: Option<bool>,
+	/// The pages of the documents to print, counting from 1.
+	/// Requires the printer to support page ranges (`page-ranges-supported`).
+	pub page_ranges: Option<Vec<RangeInclusive<u32>>>,
+	/// Determines how document pages are scaled to the media.
+	pub print_scaling: Option<PrintScaling>,
+	/// Determines whether document pages are scaled to fit the media.
+	pub fit_to_page: Option<FitToPageBool>,
+	/// Determines whether document pages are mirrored horizontally.
+	pub mirror: Option<MirrorBool>,
 	/// Color of the output.
 	pub color_mode: Option<ColorMode>,
 	/// Quality of output.
 	pub quality: Option<Quality>,
+	/// Resolution of the output.
+	pub printer_resolution: Option<Resolution>,
+	/// Determines how colors are mapped to the color space of the printer.
+	pub print_rendering_intent: Option<RenderingIntent>,
 	/// Determines single-sided or duplex printing.
 	pub sides_mode: Option<SidesMode>,
+	/// The order in which pages are output.
+	pub output_order: Option<OutputOrder>,
+	/// The output bin (tray) where the printed media is delivered to.
+	pub output_bin: Option<OutputBin>,
+	/// Determines how long the job's documents are retained after the job is completed, so that
+	/// the job can be restarted with [`crate::restart_job()`].
+	///
+	/// This is only sent if the printer advertises `job-retain-until-supported`. The CUPS
+	/// scheduler does not, so with CUPS this option is always unsupported, and handled according
+	/// to [`Self::policy`]. There, documents are retained according to the server's
+	/// `PreserveJobFiles` setting instead.
+	pub retention: Option<JobRetention>,
+	/// The priority of the job, from 1 (lowest) to 100 (highest).
+	pub job_priority: Option<JobPriorityInt>,
+	/// The banner pages printed at the start and at the end of the job.
+	pub job_sheets: Option<JobSheets>,
+	/// The ID of the account the job is charged to.
+	pub job_account_id: Option<JobAccountId>,
+	/// The ID of the user the job is accounted to.
+	pub job_accounting_user_id: Option<JobAccountingUserId>,
+	/// Determines whether documents are compressed while they are uploaded.
+	/// Compression reduces the amount of transferred data, but requires the printer (or server) to
+	/// support it (`compression-supported`).
+	pub compression: Option<Compression>,
+	/// The password (PIN) that has to be entered at the printer to release the job.
+	/// Until then, the job is held at the device.
+	pub job_password: Option<JobPassword>,
+	/// The name of the user the job is submitted as, instead of the current user.
+	/// The server may require authentication, or replace this name with the authenticated user.
+	pub requesting_user_name: Option<String>,
+	/// Options that are not covered by the other fields, as pairs of CUPS option names and values,
+	/// such as vendor-specific options (`HPEconoMode=True`), or those parsed by
+	/// [`Self::from_cups_options()`].
+	///
+	/// These options are sent as they are. They are only validated if the printer advertises the
+	/// values it supports; otherwise, they are always considered supported. If an option is also
+	/// set by one of the other fields, the field's value is used, and the change is reported as a
+	/// substitution.
+	pub extra: BTreeMap<String, String>,
+	/// Determines how options that the printer does not support are handled.
+	/// If not set, [`OptionPolicy::Strict`] is used.
+	pub policy: Option<OptionPolicy>,
+}
+
+/// A struct that defines options for a single document of a print job, overriding the options
+/// of the job.
+///
+/// These options can only be applied if the printer supports the `multiple-document-handling`
+/// attribute; see [`crate::print_documents()`].
+#[derive(Clone, Debug, Default)]
+pub struct DocumentOptions {
+	/// Amount of copies.
+	pub copies: Option<CopiesInt>,
+	/// Media size.
+	pub media_size: Option<MediaSize>,
+	/// Type of media, such as photo paper, matte paper, etc.
+	pub media_type: Option<MediaType>,
+	/// The pages of the document to print, counting from 1.
+	pub page_ranges: Option<Vec<RangeInclusive<u32>>>,
+	/// Color of the output.
+	pub color_mode: Option<ColorMode>,
+	/// Determines single-sided or duplex printing.
+	pub sides_mode: Option<SidesMode>,
+}
+
+// MARK: - Layering
+
+impl PrintOptions {
+	/// Returns a builder that validates option values before constructing the options.
+	pub fn builder() -> PrintOptionsBuilder {
+		PrintOptionsBuilder::default()
+	}
+	/// Replaces the options in this instance with those that are set in `overrides`.
+	/// Options that are not set in `overrides` are kept.
+	///
+	/// This can be used to combine several layers of options, such as system defaults, a preset,
+	/// and per-call options, in this order.
+	pub fn overlay(&mut self, overrides: PrintOptions) {
+		// Destructured, so that new fields can't be forgotten here:
+		let PrintOptions {
+			copies,
+			collate,
+			finishings,
+			finishing_templates,
+			media_size,
+			media_source,
+			media_type,
+			number_up,
+			number_up_layout,
+			page_border,
+			orientation,
+			borderless,
+			page_ranges,
+			print_scaling,
+			fit_to_page,
+			mirror,
+			color_mode,
+			quality,
+			printer_resolution,
+			print_rendering_intent,
+			sides_mode,
+			output_order,
+			output_bin,
+			retention,
+			job_priority,
+			job_sheets,
+			job_account_id,
+			job_accounting_user_id,
+			compression,
+			job_password,
+			requesting_user_name,
+			extra,
+			policy,
+		} = overrides;
+		overlay_option(&mut self.copies, copies);
+		overlay_option(&mut self.collate, collate);
+		overlay_option(&mut self.finishings, finishings);
+		overlay_option(&mut self.finishing_templates, finishing_templates);
+		overlay_option(&mut self.media_size, media_size);
+		overlay_option(&mut self.media_source, media_source);
+		overlay_option(&mut self.media_type, media_type);
+		overlay_option(&mut self.number_up, number_up);
+		overlay_option(&mut self.number_up_layout, number_up_layout);
+		overlay_option(&mut self.page_border, page_border);
+		overlay_option(&mut self.orientation, orientation);
+		overlay_option(&mut self.borderless, borderless);
+		overlay_option(&mut self.page_ranges, page_ranges);
+		overlay_option(&mut self.print_scaling, print_scaling);
+		overlay_option(&mut self.fit_to_page, fit_to_page);
+		overlay_option(&mut self.mirror, mirror);
+		overlay_option(&mut self.color_mode, color_mode);
+		overlay_option(&mut self.quality, quality);
+		overlay_option(&mut self.printer_resolution, printer_resolution);
+		overlay_option(&mut self.print_rendering_intent, print_rendering_intent);
+		overlay_option(&mut self.sides_mode, sides_mode);
+		overlay_option(&mut self.output_order, output_order);
+		overlay_option(&mut self.output_bin, output_bin);
+		overlay_option(&mut self.retention, retention);
+		overlay_option(&mut self.job_priority, job_priority);
+		overlay_option(&mut self.job_sheets, job_sheets);
+		overlay_option(&mut self.job_account_id, job_account_id);
+		overlay_option(&mut self.job_accounting_user_id, job_accounting_user_id);
+		overlay_option(&mut self.compression, compression);
+		overlay_option(&mut self.job_password, job_password);
+		overlay_option(&mut self.requesting_user_name, requesting_user_name);
+		self.extra.extend(extra);
+		overlay_option(&mut self.policy, policy);
+	}
+	/// Returns these options, with the options that are set in `overrides` taking precedence.
+	/// See [`Self::overlay()`].
+	pub fn merge(mut self, overrides: PrintOptions) -> Self {
+		self.overlay(overrides);
+		self
+	}
+}
+
+/// Replaces `target` with `value`, if `value` is set.
+fn overlay_option<T>(target: &mut Option<T>, value: Option<T>) {
+	if value.is_some() {
+		*target = value;
+	}
+}
+
+// MARK: - Option strings
+
+impl PrintOptions {
+	/// Parses options from a string in the syntax of `lp -o`, such as
+	/// `media=A4 sides=two-sided-long-edge copies=2`, as parsed by `cupsParseOptions`.
+	///
+	/// Options that correspond to a field are converted to its type; all other options (including
+	/// those with values that can't be converted) are kept in [`Self::extra`]. The classic
+	/// `collate=true|false` option is converted to [`Self::collate`].
+	pub fn from_cups_options(options: &str) -> Result<Self, PrintError> {
+		PlatformSpecificApi::parse_cups_options(options)
+	}
+	/// Formats these options as a string in the syntax of `lp -o`, which can be parsed with
+	/// [`Self::from_cups_options()`].
+	///
+	/// Settings that are not CUPS options, such as [`Self::borderless`] (which depends on the
+	/// printer) or [`Self::policy`], are not included. The job password is not included either,
+	/// so that it is not exposed; it has to be set again after parsing the string.
+	pub fn to_cups_options(&self) -> String {
+		PlatformSpecificApi::format_cups_options(self)
+	}
+}
+
+// MARK: - Builder
+
+/// Defines setters for options that don't need validation.
+macro_rules! builder_setters {
+	($($(#[$doc:meta])* $field:ident: $type:ty),* $(,)?) => {
+		$(
+			$(#[$doc])*
+			pub fn $field(mut self, value: $type) -> Self {
+				self.options.$field = Some(value);
+				self
+			}
+		)*
+	};
+}
+
+/// A builder of [`PrintOptions`], which validates option values.
+///
+/// Setters can be chained; if a value is invalid, [`Self::build()`] returns an error for the first
+/// invalid value.
+#[derive(Debug, Default)]
+pub struct PrintOptionsBuilder {
+	options: PrintOptions,
+	error: Option<PrintError>,
+}
+impl PrintOptionsBuilder {
+	/// Returns the options, or an error if any of the values was invalid.
+	pub fn build(self) -> Result<PrintOptions, PrintError> {
+		match self.error {
+			Some(error) => Err(error),
+			None => Ok(self.options),
+		}
+	}
+	/// Records an error for the option with the specified name, unless an error has already been
+	/// recorded.
+	fn invalid(mut self, name: &str, value: impl Display, reason: &str) -> Self {
+		if self.error.is_none() {
+			self.error = Some(PrintError::InvalidOption {
+				name: name.to_owned(),
+				value: value.to_string(),
+				reason: reason.to_owned(),
+			});
+		}
+		self
+	}
+
+	/// Sets the amount of copies, which must be at least 1.
+	pub fn copies(mut self, copies: c_int) -> Self {
+		if copies < 1 {
+			return self.invalid("copies", copies, "must be at least 1");
+		}
+		self.options.copies = Some(CopiesInt(copies));
+		self
+	}
+	/// Sets the number of document pages per media side, which must be at least 1.
+	pub fn number_up(mut self, number_up: c_int) -> Self {
+		if number_up < 1 {
+			return self.invalid("number up", number_up, "must be at least 1");
+		}
+		self.options.number_up = Some(NumberUpInt(number_up));
+		self
+	}
+	/// Sets the pages of the documents to print, counting from 1.
+	/// There must be at least one range; each range must start at 1 or later, and must not end
+	/// before it starts.
+	pub fn page_ranges<I>(mut self, ranges: I) -> Self
+	where
+		I: IntoIterator<Item = RangeInclusive<u32>>,
+	{
+		let ranges = ranges.into_iter().collect::<Vec<_>>();
+		if let Err(reason) = ranges.check_value() {
+			return self.invalid("page ranges", ranges.to_human_string(), reason);
+		}
+		self.options.page_ranges = Some(ranges);
+		self
+	}
+	/// Sets the resolution of the output, which must be greater than 0 in both directions.
+	pub fn printer_resolution(mut self, resolution: Resolution) -> Self {
+		if resolution.cross_feed < 1 || resolution.feed < 1 {
+			return self.invalid("resolution", resolution, "must be greater than 0");
+		}
+		self.options.printer_resolution = Some(resolution);
+		self
+	}
+	/// Sets the priority of the job, which must be between 1 (lowest) and 100 (highest).
+	pub fn job_priority(mut self, priority: c_int) -> Self {
+		let priority = JobPriorityInt(priority);
+		if let Err(reason) = priority.check_value() {
+			return self.invalid("job priority", priority.0, reason);
+		}
+		self.options.job_priority = Some(priority);
+		self
+	}
+	/// Sets the finishing processes.
+	pub fn finishings<I>(mut self, finishings: I) -> Self
+	where
+		I: IntoIterator<Item = Finishing>,
+	{
+		self.options.finishings = Some(finishings.into_iter().collect());
+		self
+	}
+	/// Sets the finishing processes by their template names.
+	pub fn finishing_templates<I, T>(mut self, templates: I) -> Self
+	where
+		I: IntoIterator<Item = T>,
+		T: Into<FinishingTemplate>,
+	{
+		self.options.finishing_templates = Some(templates.into_iter().map(Into::into).collect());
+		self
+	}
+	/// Sets whether the media is printed on edge to edge, without margins.
+	pub fn borderless(mut self, borderless: bool) -> Self {
+		self.options.borderless = Some(borderless);
+		self
+	}
+	/// Sets whether document pages are scaled to fit the media.
+	pub fn fit_to_page(mut self, fit_to_page: bool) -> Self {
+		self.options.fit_to_page = Some(FitToPageBool(fit_to_page));
+		self
+	}
+	/// Sets whether document pages are mirrored horizontally.
+	pub fn mirror(mut self, mirror: bool) -> Self {
+		self.options.mirror = Some(MirrorBool(mirror));
+		self
+	}
+	/// Sets the ID of the account the job is charged to.
+	pub fn job_account_id(mut self, id: &str) -> Self {
+		self.options.job_account_id = Some(JobAccountId::from(id));
+		self
+	}
+	/// Sets the ID of the user the job is accounted to.
+	pub fn job_accounting_user_id(mut self, id: &str) -> Self {
+		self.options.job_accounting_user_id = Some(JobAccountingUserId::from(id));
+		self
+	}
+	/// Sets the password (PIN) that has to be entered at the printer to release the job.
+	pub fn job_password(mut self, password: &str) -> Self {
+		self.options.job_password = Some(JobPassword::new(password));
+		self
+	}
+	/// Sets the name of the user the job is submitted as.
+	pub fn requesting_user_name(mut self, name: &str) -> Self {
+		self.options.requesting_user_name = Some(name.to_owned());
+		self
+	}
+
+	builder_setters! {
+		/// Sets whether copies of the documents are collated.
+		collate: Collation,
+		/// Sets the media size.
+		media_size: MediaSize,
+		/// Sets the source where the media is pulled from.
+		media_source: MediaSource,
+		/// Sets the type of media.
+		media_type: MediaType,
+		/// Sets the order in which document pages are placed on a media side.
+		number_up_layout: NumberUpLayout,
+		/// Sets the border drawn around each document page.
+		page_border: PageBorder,
+		/// Sets the orientation of document pages on the media.
+		orientation: Orientation,
+		/// Sets how document pages are scaled to the media.
+		print_scaling: PrintScaling,
+		/// Sets the color of the output.
+		color_mode: ColorMode,
+		/// Sets the quality of the output.
+		quality: Quality,
+		/// Sets how colors are mapped to the color space of the printer.
+		print_rendering_intent: RenderingIntent,
+		/// Sets single-sided or duplex printing.
+		sides_mode: SidesMode,
+		/// Sets the order in which pages are output.
+		output_order: OutputOrder,
+		/// Sets the output bin where the printed media is delivered to.
+		output_bin: OutputBin,
+		/// Sets how long the job's documents are retained after the job is completed.
+		retention: JobRetention,
+		/// Sets the banner pages printed at the start and at the end of the job.
+		job_sheets: JobSheets,
+		/// Sets whether documents are compressed while they are uploaded.
+		compression: Compression,
+		/// Sets how options that the printer does not support are handled.
+		policy: OptionPolicy,
+	}
 }
 
+/// Determines how options that the printer does not support are handled.
+#[derive(Clone, Copy, Debug, Default, strum::Display, strum::EnumString, strum::VariantNames)]
+#[strum(serialize_all = "kebab-case")]
+pub enum OptionPolicy {
+	/// Printing fails with [`crate::error::PrintError::UnsupportedOption`].
+	#[default]
+	Strict,
+	/// Unsupported options are removed.
+	DropUnsupported,
+	/// Unsupported options are replaced with the closest value that the printer supports.
+	/// If there is no such value, the option is removed.
+	NearestSupported,
+}
+impl PrintOptionVariants for OptionPolicy {}
+
 /// Designates a print option.
 pub trait PrintOption {
 	fn get_name() -> &'static str;
 	fn to_human_string(&self) -> String;
+	/// Returns values that can be used in place of this value if it is not supported,
+	/// closest first.
+	fn get_fallbacks(&self) -> Vec<Self>
+	where
+		Self: Sized,
+	{
+		Vec::new()
+	}
+	/// Checks whether this value is valid, regardless of the printer.
+	/// Returns the reason if it is not.
+	fn check_value(&self) -> Result<(), &'static str> {
+		Ok(())
+	}
+}
+
+/// Designates a print option that can be converted from the value of an IPP attribute, such as a
+/// printer's default (`media-default`), a job's attribute, or an option in an `lpoptions` file.
+///
+/// Options implement `TryFrom<&str>` with the same conversion, for example
+/// `MediaSize::try_from("iso_a4_210x297mm")`. Lists (such as `Vec<Finishing>`) can't implement
+/// it, so [`FromIpp::try_from_ipp()`] has to be used for them. For enums, variant names (such as
+/// `a4`) are accepted as well, ignoring case; [`FromStr`] only parses variant names.
+///
+/// Most of these attributes are keywords, integers, or strings. Only `finishings`,
+/// `orientation-requested` and `print-quality` are IPP enums, whose integer values can also be
+/// converted with [`Finishing::from_ipp_enum()`], [`Orientation::from_ipp_enum()`] and
+/// [`Quality::from_ipp_enum()`].
+pub trait FromIpp: PrintOption + Sized {
+	/// Converts an IPP value (a keyword, or the string form of an enum value or an integer) to the
+	/// option's type. Returns `None` if the value does not correspond to a value of this type.
+	fn from_ipp(value: &str) -> Option<Self>;
+	/// Converts an IPP value to the option's type.
+	/// Returns an error if the value does not correspond to a value of this type.
+	fn try_from_ipp(value: &str) -> Result<Self, PrintError> {
+		Self::from_ipp(value).ok_or_else(|| PrintError::InvalidOption {
+			name: Self::get_name().to_lowercase(),
+			value: value.to_owned(),
+			reason: String::from("not a known IPP value"),
+		})
+	}
 }
 
 // Encapsulate strum::VariantNames, so that users don't have to add strum as a dependency.
@@ -42,8 +501,12 @@ pub trait PrintOptionVariants: strum::VariantNames {
 }
 
 /// Provides a basic implementation of [`PrintOption`], by specifying the type and its name.
+/// Optionally, a function returning the fallbacks of a value can be specified.
 macro_rules! impl_print_option {
 	($type:ty => $name:expr) => {
+		impl_print_option!($type => $name, |_value| Vec::new());
+	};
+	($type:ty => $name:expr, $fallbacks:expr) => {
 		impl PrintOption for $type {
 			fn get_name() -> &'static str {
 				$name
@@ -51,10 +514,77 @@ macro_rules! impl_print_option {
 			fn to_human_string(&self) -> String {
 				format!("{self}")
 			}
+			fn get_fallbacks(&self) -> Vec<Self> {
+				let fallbacks: fn(&Self) -> Vec<Self> = $fallbacks;
+				fallbacks(self)
+			}
+		}
+	};
+}
+
+/// Implements [`FromStr`] for an enum, by matching the names of its variants (as displayed), such
+/// as `a4`. This is used instead of `strum::EnumString`, which implements `TryFrom<&str>` as well.
+macro_rules! impl_from_str_by_name {
+	($type:ty) => {
+		impl FromStr for $type {
+			type Err = strum::ParseError;
+			fn from_str(s: &str) -> Result<Self, Self::Err> {
+				<Self as strum::IntoEnumIterator>::iter()
+					.find(|variant| variant.to_string() == s)
+					.ok_or(strum::ParseError::VariantNotFound)
+			}
 		}
 	};
 }
 
+/// Implements [`FromIpp`] and `TryFrom<&str>` for an enum, by specifying the IPP value of each
+/// variant. Variant names are accepted as well, ignoring case.
+macro_rules! impl_from_ipp {
+	($type:ty { $($value:literal => $variant:expr),* $(,)? }) => {
+		impl FromIpp for $type {
+			fn from_ipp(value: &str) -> Option<Self> {
+				match value {
+					$($value => Some($variant),)*
+					_ => parse_variant_name(value),
+				}
+			}
+		}
+		impl_try_from_ipp!($type);
+	};
+}
+
+/// Implements `TryFrom<&str>` for an option, using [`FromIpp::try_from_ipp()`].
+macro_rules! impl_try_from_ipp {
+	($type:ty) => {
+		impl TryFrom<&str> for $type {
+			type Error = PrintError;
+			fn try_from(value: &str) -> Result<Self, Self::Error> {
+				Self::try_from_ipp(value)
+			}
+		}
+	};
+}
+
+/// Finds the variant of an enum whose name (such as `a4`) is `value`, ignoring case.
+fn parse_variant_name<O>(value: &str) -> Option<O>
+where
+	O: FromStr + strum::VariantNames,
+{
+	O::VARIANTS
+		.iter()
+		.find(|name| name.eq_ignore_ascii_case(value))
+		.and_then(|name| name.parse().ok())
+}
+
+/// Parses a boolean IPP or CUPS option value, such as `true` or `no`.
+pub(crate) fn parse_bool(value: &str) -> Option<bool> {
+	match value.to_ascii_lowercase().as_str() {
+		"true" | "yes" | "on" => Some(true),
+		"false" | "no" | "off" => Some(false),
+		_ => None,
+	}
+}
+
 // MARK: - Conrete Options
 
 #[derive(Clone, Copy, Debug)]
@@ -72,6 +602,12 @@ impl PrintOption for CopiesInt {
 		self.0.to_string()
 	}
 }
+impl FromIpp for CopiesInt {
+	fn from_ipp(value: &str) -> Option<Self> {
+		value.parse().ok().map(Self)
+	}
+}
+impl_try_from_ipp!(CopiesInt);
 
 #[derive(Clone, Copy, Debug)]
 pub struct NumberUpInt(pub c_int);
@@ -87,20 +623,328 @@ impl PrintOption for NumberUpInt {
 	fn to_human_string(&self) -> String {
 		self.0.to_string()
 	}
+	fn get_fallbacks(&self) -> Vec<Self> {
+		// Fewer pages per side, down to one
+		(1..self.0).rev().map(Self).collect()
+	}
+}
+impl FromIpp for NumberUpInt {
+	fn from_ipp(value: &str) -> Option<Self> {
+		value.parse().ok().map(Self)
+	}
+}
+impl_try_from_ipp!(NumberUpInt);
+
+#[derive(Clone, Copy, Debug, strum::Display, strum::EnumIter, strum::VariantNames)]
+#[strum(serialize_all = "kebab-case")]
+pub enum NumberUpLayout {
+	/// Left to right, top to bottom.
+	Lrtb,
+	/// Left to right, bottom to top.
+	Lrbt,
+	/// Right to left, top to bottom.
+	Rltb,
+	/// Right to left, bottom to top.
+	Rlbt,
+	/// Top to bottom, left to right.
+	Tblr,
+	/// Top to bottom, right to left.
+	Tbrl,
+	/// Bottom to top, left to right.
+	Btlr,
+	/// Bottom to top, right to left.
+	Btrl,
+}
+impl_print_option!(NumberUpLayout => "Number Up Layout");
+impl PrintOptionVariants for NumberUpLayout {}
+impl_from_str_by_name!(NumberUpLayout);
+impl_from_ipp!(NumberUpLayout {
+	"lrtb" => NumberUpLayout::Lrtb,
+	"lrbt" => NumberUpLayout::Lrbt,
+	"rltb" => NumberUpLayout::Rltb,
+	"rlbt" => NumberUpLayout::Rlbt,
+	"tblr" => NumberUpLayout::Tblr,
+	"tbrl" => NumberUpLayout::Tbrl,
+	"btlr" => NumberUpLayout::Btlr,
+	"btrl" => NumberUpLayout::Btrl,
+});
+
+#[derive(Clone, Copy, Debug, strum::Display, strum::EnumIter, strum::VariantNames)]
+#[strum(serialize_all = "kebab-case")]
+pub enum PageBorder {
+	None,
+	Single,
+	SingleThick,
+	Double,
+	DoubleThick,
+}
+impl_print_option!(PageBorder => "Page Border", |value| match value {
+	PageBorder::None => Vec::new(),
+	PageBorder::SingleThick => vec![PageBorder::Single, PageBorder::None],
+	PageBorder::DoubleThick => vec![PageBorder::Double, PageBorder::None],
+	_ => vec![PageBorder::None],
+});
+impl PrintOptionVariants for PageBorder {}
+impl_from_str_by_name!(PageBorder);
+impl_from_ipp!(PageBorder {
+	"none" => PageBorder::None,
+	"single" => PageBorder::Single,
+	"single-thick" => PageBorder::SingleThick,
+	"double" => PageBorder::Double,
+	"double-thick" => PageBorder::DoubleThick,
+});
+
+#[derive(Clone, Copy, Debug, strum::Display, strum::EnumIter, strum::VariantNames)]
+#[strum(serialize_all = "kebab-case")]
+pub enum PrintScaling {
+	/// Scales pages to fit the printable area if they don't fit, or fills the media if the
+	/// document has no margins.
+	Auto,
+	/// Scales pages to fit the printable area only if they don't fit.
+	AutoFit,
+	/// Scales pages to fill the media, cropping them if necessary.
+	Fill,
+	/// Scales pages to fit the printable area, preserving the aspect ratio.
+	Fit,
+	/// Does not scale pages (1:1 output).
+	None,
+}
+// No fallbacks: substituting another scaling mode would change the output size.
+impl_print_option!(PrintScaling => "Print Scaling");
+impl PrintOptionVariants for PrintScaling {}
+impl_from_str_by_name!(PrintScaling);
+impl_from_ipp!(PrintScaling {
+	"auto" => PrintScaling::Auto,
+	"auto-fit" => PrintScaling::AutoFit,
+	"fill" => PrintScaling::Fill,
+	"fit" => PrintScaling::Fit,
+	"none" => PrintScaling::None,
+});
+
+#[derive(Clone, Copy, Debug)]
+pub struct FitToPageBool(pub bool);
+impl From<bool> for FitToPageBool {
+	fn from(value: bool) -> Self {
+		Self(value)
+	}
+}
+impl PrintOption for FitToPageBool {
+	fn get_name() -> &'static str {
+		"Fit To Page"
+	}
+	fn to_human_string(&self) -> String {
+		self.0.to_string()
+	}
 }
+impl FromIpp for FitToPageBool {
+	fn from_ipp(value: &str) -> Option<Self> {
+		parse_bool(value).map(Self)
+	}
+}
+impl_try_from_ipp!(FitToPageBool);
 
-#[derive(Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantNames)]
+#[derive(Clone, Copy, Debug)]
+pub struct MirrorBool(pub bool);
+impl From<bool> for MirrorBool {
+	fn from(value: bool) -> Self {
+		Self(value)
+	}
+}
+impl PrintOption for MirrorBool {
+	fn get_name() -> &'static str {
+		"Mirror"
+	}
+	fn to_human_string(&self) -> String {
+		self.0.to_string()
+	}
+}
+impl FromIpp for MirrorBool {
+	fn from_ipp(value: &str) -> Option<Self> {
+		parse_bool(value).map(Self)
+	}
+}
+impl_try_from_ipp!(MirrorBool);
+
+#[derive(Clone, Copy, Debug, strum::Display, strum::EnumIter, strum::VariantNames)]
 #[strum(serialize_all = "kebab-case")]
+pub enum Collation {
+	/// Copies are output as complete sets (1, 2, 3, 1, 2, 3).
+	Collated,
+	/// Copies of each page are output together (1, 1, 2, 2, 3, 3).
+	Uncollated,
+}
+impl_print_option!(Collation => "Collate");
+impl PrintOptionVariants for Collation {}
+impl_from_str_by_name!(Collation);
+impl_from_ipp!(Collation {
+	"separate-documents-collated-copies" => Collation::Collated,
+	"separate-documents-uncollated-copies" => Collation::Uncollated,
+});
+
+#[derive(Clone, Copy, Debug, strum::Display, strum::EnumIter, strum::VariantNames)]
+#[strum(serialize_all = "kebab-case")]
+/// A finishing process, as defined in PWG 5100.1.
+///
+/// Positional variants (such as [`Finishing::StapleTopLeft`]) refer to the position relative to
+/// the portrait orientation of the document.
 pub enum Finishing {
-	Bind,
+	// Basic finishings
+	Staple,
+	Punch,
 	Cover,
+	Bind,
+	SaddleStitch,
+	EdgeStitch,
 	Fold,
-	Punch,
-	Staple,
 	Trim,
+	Bale,
+	BookletMaker,
+	JogOffset,
+	Coat,
+	Laminate,
+	// Stapling and stitching
+	StapleTopLeft,
+	StapleBottomLeft,
+	StapleTopRight,
+	StapleBottomRight,
+	EdgeStitchLeft,
+	EdgeStitchTop,
+	EdgeStitchRight,
+	EdgeStitchBottom,
+	StapleDualLeft,
+	StapleDualTop,
+	StapleDualRight,
+	StapleDualBottom,
+	StapleTripleLeft,
+	StapleTripleTop,
+	StapleTripleRight,
+	StapleTripleBottom,
+	// Binding
+	BindLeft,
+	BindTop,
+	BindRight,
+	BindBottom,
+	// Trimming
+	TrimAfterPages,
+	TrimAfterDocuments,
+	TrimAfterCopies,
+	TrimAfterJob,
+	// Punching
+	PunchTopLeft,
+	PunchBottomLeft,
+	PunchTopRight,
+	PunchBottomRight,
+	PunchDualLeft,
+	PunchDualTop,
+	PunchDualRight,
+	PunchDualBottom,
+	PunchTripleLeft,
+	PunchTripleTop,
+	PunchTripleRight,
+	PunchTripleBottom,
+	PunchQuadLeft,
+	PunchQuadTop,
+	PunchQuadRight,
+	PunchQuadBottom,
+	PunchMultipleLeft,
+	PunchMultipleTop,
+	PunchMultipleRight,
+	PunchMultipleBottom,
+	// Folding
+	FoldAccordion,
+	FoldDoubleGate,
+	FoldGate,
+	FoldHalf,
+	FoldHalfZ,
+	FoldLeftGate,
+	FoldLetter,
+	FoldParallel,
+	FoldPoster,
+	FoldRightGate,
+	FoldZ,
+	FoldEngineeringZ,
 }
 impl_print_option!(Finishing => "Finishing");
 impl PrintOptionVariants for Finishing {}
+impl_from_str_by_name!(Finishing);
+impl_from_ipp!(Finishing {
+	"4" => Finishing::Staple,
+	"5" => Finishing::Punch,
+	"6" => Finishing::Cover,
+	"7" => Finishing::Bind,
+	"8" => Finishing::SaddleStitch,
+	"9" => Finishing::EdgeStitch,
+	"10" => Finishing::Fold,
+	"11" => Finishing::Trim,
+	"12" => Finishing::Bale,
+	"13" => Finishing::BookletMaker,
+	"14" => Finishing::JogOffset,
+	"15" => Finishing::Coat,
+	"16" => Finishing::Laminate,
+	"20" => Finishing::StapleTopLeft,
+	"21" => Finishing::StapleBottomLeft,
+	"22" => Finishing::StapleTopRight,
+	"23" => Finishing::StapleBottomRight,
+	"24" => Finishing::EdgeStitchLeft,
+	"25" => Finishing::EdgeStitchTop,
+	"26" => Finishing::EdgeStitchRight,
+	"27" => Finishing::EdgeStitchBottom,
+	"28" => Finishing::StapleDualLeft,
+	"29" => Finishing::StapleDualTop,
+	"30" => Finishing::StapleDualRight,
+	"31" => Finishing::StapleDualBottom,
+	"32" => Finishing::StapleTripleLeft,
+	"33" => Finishing::StapleTripleTop,
+	"34" => Finishing::StapleTripleRight,
+	"35" => Finishing::StapleTripleBottom,
+	"50" => Finishing::BindLeft,
+	"51" => Finishing::BindTop,
+	"52" => Finishing::BindRight,
+	"53" => Finishing::BindBottom,
+	"60" => Finishing::TrimAfterPages,
+	"61" => Finishing::TrimAfterDocuments,
+	"62" => Finishing::TrimAfterCopies,
+	"63" => Finishing::TrimAfterJob,
+	"70" => Finishing::PunchTopLeft,
+	"71" => Finishing::PunchBottomLeft,
+	"72" => Finishing::PunchTopRight,
+	"73" => Finishing::PunchBottomRight,
+	"74" => Finishing::PunchDualLeft,
+	"75" => Finishing::PunchDualTop,
+	"76" => Finishing::PunchDualRight,
+	"77" => Finishing::PunchDualBottom,
+	"78" => Finishing::PunchTripleLeft,
+	"79" => Finishing::PunchTripleTop,
+	"80" => Finishing::PunchTripleRight,
+	"81" => Finishing::PunchTripleBottom,
+	"82" => Finishing::PunchQuadLeft,
+	"83" => Finishing::PunchQuadTop,
+	"84" => Finishing::PunchQuadRight,
+	"85" => Finishing::PunchQuadBottom,
+	"86" => Finishing::PunchMultipleLeft,
+	"87" => Finishing::PunchMultipleTop,
+	"88" => Finishing::PunchMultipleRight,
+	"89" => Finishing::PunchMultipleBottom,
+	"90" => Finishing::FoldAccordion,
+	"91" => Finishing::FoldDoubleGate,
+	"92" => Finishing::FoldGate,
+	"93" => Finishing::FoldHalf,
+	"94" => Finishing::FoldHalfZ,
+	"95" => Finishing::FoldLeftGate,
+	"96" => Finishing::FoldLetter,
+	"97" => Finishing::FoldParallel,
+	"98" => Finishing::FoldPoster,
+	"99" => Finishing::FoldRightGate,
+	"100" => Finishing::FoldZ,
+	"101" => Finishing::FoldEngineeringZ,
+});
+impl Finishing {
+	/// Converts a value of the IPP `finishings` enum, such as 4 (`staple`), to a finishing.
+	/// Returns `None` if the value does not correspond to a finishing.
+	pub fn from_ipp_enum(value: i32) -> Option<Self> {
+		Self::from_ipp(&value.to_string())
+	}
+}
 
 impl PrintOption for Vec<Finishing> {
 	fn get_name() -> &'static str {
@@ -113,8 +957,102 @@ impl PrintOption for Vec<Finishing> {
 			.join(", ")
 	}
 }
+impl FromIpp for Vec<Finishing> {
+	fn from_ipp(value: &str) -> Option<Self> {
+		// The `none` finishing (3) corresponds to an empty list:
+		if value == "3" {
+			return Some(Vec::new());
+		}
+		value.split(',').map(Finishing::from_ipp).collect()
+	}
+}
 
-#[derive(Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantNames)]
+/// The name of a finishing template, such as `staple-top-left`, or a vendor-specific name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FinishingTemplate(pub String);
+impl From<Finishing> for FinishingTemplate {
+	fn from(value: Finishing) -> Self {
+		// The template names of standard finishings are their keywords:
+		Self(value.to_string())
+	}
+}
+impl From<&str> for FinishingTemplate {
+	fn from(value: &str) -> Self {
+		Self(value.to_owned())
+	}
+}
+
+impl PrintOption for Vec<FinishingTemplate> {
+	fn get_name() -> &'static str {
+		"Finishing Templates"
+	}
+	fn to_human_string(&self) -> String {
+		self.iter()
+			.map(|template| template.0.as_str())
+			.collect::<Vec<_>>()
+			.join(", ")
+	}
+}
+impl FromIpp for Vec<FinishingTemplate> {
+	fn from_ipp(value: &str) -> Option<Self> {
+		// Such as `{finishing-template=staple-top-left},{finishing-template=punch-dual-left}`:
+		split_collections(value)
+			.into_iter()
+			.map(|collection| {
+				let member = collection.trim().strip_prefix('{')?.strip_suffix('}')?;
+				let template = member.trim().strip_prefix("finishing-template=")?;
+				Some(FinishingTemplate::from(unquote_value(template).as_str()))
+			})
+			.collect()
+	}
+}
+
+/// Removes the quotes and escapes from a value within a collection, such as `"a,b"`.
+/// Values without quotes are returned unchanged.
+fn unquote_value(value: &str) -> String {
+	let Some(quoted) = value
+		.strip_prefix('"')
+		.and_then(|value| value.strip_suffix('"'))
+	else {
+		return value.to_owned();
+	};
+	let mut unquoted = String::with_capacity(quoted.len());
+	let mut chars = quoted.chars();
+	while let Some(c) = chars.next() {
+		match c {
+			'\\' => unquoted.extend(chars.next()),
+			c => unquoted.push(c),
+		}
+	}
+	unquoted
+}
+
+/// Splits a comma-separated list of collections (such as `{a=1},{a="2,3"}`), ignoring commas
+/// within braces and quotes.
+fn split_collections(value: &str) -> Vec<&str> {
+	let mut collections = Vec::new();
+	let (mut depth, mut quote, mut escaped, mut start) = (0usize, None, false, 0);
+	for (index, c) in value.char_indices() {
+		match (c, quote) {
+			_ if escaped => escaped = false,
+			('\\', _) => escaped = true,
+			('"' | '\'', None) => quote = Some(c),
+			(c, Some(q)) if c == q => quote = None,
+			(_, Some(_)) => {}
+			('{', None) => depth += 1,
+			('}', None) => depth = depth.saturating_sub(1),
+			(',', None) if depth == 0 => {
+				collections.push(&value[start..index]);
+				start = index + 1;
+			}
+			_ => {}
+		}
+	}
+	collections.push(&value[start..]);
+	collections
+}
+
+#[derive(Clone, Copy, Debug, strum::Display, strum::EnumIter, strum::VariantNames)]
 #[strum(serialize_all = "kebab-case")]
 pub enum MediaSize {
 	// ISO & A3+
@@ -136,19 +1074,176 @@ pub enum MediaSize {
 	EnvelopeDL,
 	Photo3R,
 }
-impl_print_option!(MediaSize => "Media Size");
+impl_print_option!(MediaSize => "Media Size", |value| match value {
+	MediaSize::A3 => vec![MediaSize::Tabloid],
+	MediaSize::A3Plus => vec![MediaSize::A3, MediaSize::Tabloid],
+	MediaSize::A4 => vec![MediaSize::Letter],
+	MediaSize::GovtLetter => vec![MediaSize::Letter, MediaSize::A4],
+	MediaSize::Letter => vec![MediaSize::A4],
+	MediaSize::Tabloid => vec![MediaSize::A3],
+	MediaSize::Envelope10 => vec![MediaSize::EnvelopeDL],
+	MediaSize::EnvelopeDL => vec![MediaSize::Envelope10],
+	_ => Vec::new(),
+});
 impl PrintOptionVariants for MediaSize {}
+impl_from_str_by_name!(MediaSize);
+impl_from_ipp!(MediaSize {
+	"iso_a3_297x420mm" => MediaSize::A3,
+	"na_super-b_13x19in" => MediaSize::A3Plus,
+	"iso_a4_210x297mm" => MediaSize::A4,
+	"iso_a5_148x210mm" => MediaSize::A5,
+	"iso_a6_105x148mm" => MediaSize::A6,
+	"na_govt-letter_8x10in" => MediaSize::GovtLetter,
+	"na_letter_8.5x11in" => MediaSize::Letter,
+	"na_legal_8.5x14in" => MediaSize::Legal,
+	"na_ledger_11x17in" => MediaSize::Tabloid,
+	"na_index-3x5_3x5in" => MediaSize::Index3x5,
+	"na_index-4x6_4x6in" => MediaSize::Index4x6,
+	"na_5x7_5x7in" => MediaSize::Index5x7,
+	"na_number-10_4.125x9.5in" => MediaSize::Envelope10,
+	"iso_dl_110x220mm" => MediaSize::EnvelopeDL,
+	"oe_photo-l_3.5x5in" => MediaSize::Photo3R,
+});
 
-#[derive(Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantNames)]
-#[strum(serialize_all = "kebab-case")]
+/// The source where the media is pulled from, as an IPP `media-source` keyword.
+///
+/// The sources a printer supports are listed in its `media-source-supported` attribute.
+/// Sources without a variant (for example, vendor-specific ones) can be specified with
+/// [`MediaSource::Other`].
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum MediaSource {
 	Auto,
+	Main,
+	Alternate,
+	LargeCapacity,
 	Manual,
+	Envelope,
+	EnvelopeManual,
+	ByPassTray,
+	Top,
+	Middle,
+	Bottom,
+	Side,
+	Left,
+	Right,
+	Center,
+	Rear,
+	Disc,
+	Photo,
+	/// A numbered tray (`tray-1`, `tray-2`, ...).
+	Tray(u32),
+	/// A numbered roll (`roll-1`, `roll-2`, ...).
+	Roll(u32),
+	/// Any other source, specified by its keyword.
+	Other(String),
+}
+impl MediaSource {
+	/// The sources that are specified by a fixed keyword.
+	const NAMED: [MediaSource; 18] = [
+		Self::Auto,
+		Self::Main,
+		Self::Alternate,
+		Self::LargeCapacity,
+		Self::Manual,
+		Self::Envelope,
+		Self::EnvelopeManual,
+		Self::ByPassTray,
+		Self::Top,
+		Self::Middle,
+		Self::Bottom,
+		Self::Side,
+		Self::Left,
+		Self::Right,
+		Self::Center,
+		Self::Rear,
+		Self::Disc,
+		Self::Photo,
+	];
+	/// Returns the fixed keyword of this source, or `None` if it is numbered or custom.
+	fn get_keyword(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::Auto => "auto",
+			Self::Main => "main",
+			Self::Alternate => "alternate",
+			Self::LargeCapacity => "large-capacity",
+			Self::Manual => "manual",
+			Self::Envelope => "envelope",
+			Self::EnvelopeManual => "envelope-manual",
+			Self::ByPassTray => "by-pass-tray",
+			Self::Top => "top",
+			Self::Middle => "middle",
+			Self::Bottom => "bottom",
+			Self::Side => "side",
+			Self::Left => "left",
+			Self::Right => "right",
+			Self::Center => "center",
+			Self::Rear => "rear",
+			Self::Disc => "disc",
+			Self::Photo => "photo",
+			Self::Tray(_) | Self::Roll(_) | Self::Other(_) => return None,
+		})
+	}
+}
+impl Display for MediaSource {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Tray(number) => write!(f, "tray-{number}"),
+			Self::Roll(number) => write!(f, "roll-{number}"),
+			Self::Other(keyword) => f.write_str(keyword),
+			named => f.write_str(named.get_keyword().unwrap_or_default()),
+		}
+	}
+}
+impl FromStr for MediaSource {
+	type Err = Infallible;
+	/// Parses a `media-source` keyword. Unknown keywords are parsed into [`MediaSource::Other`].
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let named = Self::NAMED
+			.into_iter()
+			.find(|source| source.get_keyword() == Some(s));
+		if let Some(named) = named {
+			return Ok(named);
+		}
+		let numbered = |prefix: &str| s.strip_prefix(prefix)?.parse::<u32>().ok();
+		if let Some(number) = numbered("tray-") {
+			return Ok(Self::Tray(number));
+		}
+		if let Some(number) = numbered("roll-") {
+			return Ok(Self::Roll(number));
+		}
+		Ok(Self::Other(s.to_owned()))
+	}
 }
-impl_print_option!(MediaSource => "Media Source");
+impl strum::VariantNames for MediaSource {
+	const VARIANTS: &'static [&'static str] = &[
+		"auto",
+		"main",
+		"alternate",
+		"large-capacity",
+		"manual",
+		"envelope",
+		"envelope-manual",
+		"by-pass-tray",
+		"top",
+		"middle",
+		"bottom",
+		"side",
+		"left",
+		"right",
+		"center",
+		"rear",
+		"disc",
+		"photo",
+	];
+}
+impl_print_option!(MediaSource => "Media Source", |value| match value {
+	MediaSource::Auto => Vec::new(),
+	MediaSource::Manual => vec![MediaSource::ByPassTray, MediaSource::Auto],
+	_ => vec![MediaSource::Auto],
+});
 impl PrintOptionVariants for MediaSource {}
 
-#[derive(Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantNames)]
+#[derive(Clone, Copy, Debug, strum::Display, strum::EnumIter, strum::VariantNames)]
 #[strum(serialize_all = "kebab-case")]
 pub enum MediaType {
 	Auto,
@@ -161,44 +1256,1058 @@ pub enum MediaType {
 	Plain,
 	Transparent,
 }
-impl_print_option!(MediaType => "Media Type");
+impl_print_option!(MediaType => "Media Type", |value| match value {
+	MediaType::Auto => Vec::new(),
+	MediaType::PhotoGlossy | MediaType::PhotoMatte => vec![MediaType::Photo, MediaType::Auto],
+	MediaType::Letterhead => vec![MediaType::Plain, MediaType::Auto],
+	_ => vec![MediaType::Auto],
+});
+impl FromIpp for MediaSource {
+	fn from_ipp(value: &str) -> Option<Self> {
+		value.parse().ok()
+	}
+}
+impl_try_from_ipp!(MediaSource);
 impl PrintOptionVariants for MediaType {}
+impl_from_str_by_name!(MediaType);
+impl_from_ipp!(MediaType {
+	"auto" => MediaType::Auto,
+	"envelope" => MediaType::Envelope,
+	"labels" => MediaType::Labels,
+	"stationery-letterhead" => MediaType::Letterhead,
+	"photographic" => MediaType::Photo,
+	"photographic-glossy" => MediaType::PhotoGlossy,
+	"photographic-matte" => MediaType::PhotoMatte,
+	"stationery" => MediaType::Plain,
+	"transparency" => MediaType::Transparent,
+});
 
-#[derive(Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantNames)]
+#[derive(Clone, Copy, Debug, strum::Display, strum::EnumIter, strum::VariantNames)]
 #[strum(serialize_all = "kebab-case")]
 pub enum Orientation {
 	Portrait,
 	Landscape,
+	/// Landscape, rotated by 180 degrees.
+	ReverseLandscape,
+	/// Portrait, rotated by 180 degrees.
+	ReversePortrait,
+	/// The document is not rotated.
+	None,
 }
-impl_print_option!(Orientation => "Orientation");
+impl_print_option!(Orientation => "Orientation", |value| match value {
+	Orientation::ReverseLandscape => vec![Orientation::Landscape],
+	Orientation::ReversePortrait => vec![Orientation::Portrait],
+	_ => Vec::new(),
+});
 impl PrintOptionVariants for Orientation {}
+impl_from_str_by_name!(Orientation);
+impl_from_ipp!(Orientation {
+	"3" => Orientation::Portrait,
+	"4" => Orientation::Landscape,
+	"5" => Orientation::ReverseLandscape,
+	"6" => Orientation::ReversePortrait,
+	"7" => Orientation::None,
+});
+impl Orientation {
+	/// Converts a value of the IPP `orientation-requested` enum, such as 4 (`landscape`), to an
+	/// orientation. Returns `None` if the value does not correspond to an orientation.
+	pub fn from_ipp_enum(value: i32) -> Option<Self> {
+		Self::from_ipp(&value.to_string())
+	}
+}
 
-#[derive(Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantNames)]
+impl PrintOption for Vec<RangeInclusive<u32>> {
+	fn get_name() -> &'static str {
+		"Page Ranges"
+	}
+	fn to_human_string(&self) -> String {
+		self.iter()
+			.map(|range| format!("{}-{}", range.start(), range.end()))
+			.collect::<Vec<_>>()
+			.join(", ")
+	}
+	fn check_value(&self) -> Result<(), &'static str> {
+		if self.is_empty() {
+			return Err("must contain at least one range");
+		}
+		if self
+			.iter()
+			.any(|range| *range.start() < 1 || range.start() > range.end())
+		{
+			return Err("must be non-empty ranges of pages from 1");
+		}
+		Ok(())
+	}
+}
+impl FromIpp for Vec<RangeInclusive<u32>> {
+	fn from_ipp(value: &str) -> Option<Self> {
+		// Such as `1-3,5`:
+		value
+			.split(',')
+			.map(|range| match range.split_once('-') {
+				Some((start, end)) => Some(start.trim().parse().ok()?..=end.trim().parse().ok()?),
+				None => range.trim().parse().ok().map(|page| page..=page),
+			})
+			.collect::<Option<Self>>()
+			.filter(|ranges| ranges.check_value().is_ok())
+	}
+}
+
+#[derive(Clone, Copy, Debug, strum::Display, strum::EnumIter, strum::VariantNames)]
 #[strum(serialize_all = "kebab-case")]
 pub enum ColorMode {
 	Auto,
 	Monochrome,
 	Color,
+	/// Black and white only, without shades of gray.
+	BiLevel,
+	/// Monochrome, using the colorants of the printer (for example, composite black).
+	ProcessMonochrome,
+	/// Black plus a single highlight color.
+	Highlight,
+	/// Monochrome, using either `monochrome` or `process-monochrome` as chosen by the printer.
+	AutoMonochrome,
 }
-impl_print_option!(ColorMode => "Color Mode");
+// Monochrome modes only fall back to other monochrome modes, so that they are never printed in
+// color:
+impl_print_option!(ColorMode => "Color Mode", |value| match value {
+	ColorMode::Auto => Vec::new(),
+	ColorMode::Monochrome => vec![ColorMode::AutoMonochrome, ColorMode::ProcessMonochrome],
+	ColorMode::Color => vec![ColorMode::Auto, ColorMode::Monochrome],
+	ColorMode::BiLevel => vec![ColorMode::Monochrome, ColorMode::ProcessMonochrome],
+	ColorMode::ProcessMonochrome => vec![ColorMode::Monochrome, ColorMode::AutoMonochrome],
+	ColorMode::AutoMonochrome => vec![ColorMode::Monochrome, ColorMode::ProcessMonochrome],
+	ColorMode::Highlight => vec![ColorMode::Color, ColorMode::Auto],
+});
 impl PrintOptionVariants for ColorMode {}
+impl_from_str_by_name!(ColorMode);
+impl_from_ipp!(ColorMode {
+	"auto" => ColorMode::Auto,
+	"monochrome" => ColorMode::Monochrome,
+	"color" => ColorMode::Color,
+	"bi-level" => ColorMode::BiLevel,
+	"process-monochrome" => ColorMode::ProcessMonochrome,
+	"highlight" => ColorMode::Highlight,
+	"auto-monochrome" => ColorMode::AutoMonochrome,
+});
 
-#[derive(Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantNames)]
+#[derive(Clone, Copy, Debug, strum::Display, strum::EnumIter, strum::VariantNames)]
 #[strum(serialize_all = "kebab-case")]
 pub enum Quality {
 	Draft,
 	Normal,
 	High,
 }
-impl_print_option!(Quality => "Quality");
+impl_print_option!(Quality => "Quality", |value| match value {
+	Quality::Draft => vec![Quality::Normal, Quality::High],
+	Quality::Normal => vec![Quality::Draft, Quality::High],
+	Quality::High => vec![Quality::Normal, Quality::Draft],
+});
 impl PrintOptionVariants for Quality {}
+impl_from_str_by_name!(Quality);
+impl_from_ipp!(Quality {
+	"3" => Quality::Draft,
+	"4" => Quality::Normal,
+	"5" => Quality::High,
+});
+impl Quality {
+	/// Converts a value of the IPP `print-quality` enum, such as 5 (`high`), to a quality.
+	/// Returns `None` if the value does not correspond to a quality.
+	pub fn from_ipp_enum(value: i32) -> Option<Self> {
+		Self::from_ipp(&value.to_string())
+	}
+}
+
+/// A print resolution, as cross-feed (horizontal) and feed (vertical) resolution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Resolution {
+	/// The resolution in the cross-feed direction.
+	pub cross_feed: c_int,
+	/// The resolution in the feed direction.
+	pub feed: c_int,
+	/// The units of the resolution.
+	pub units: ResolutionUnits,
+}
+impl Resolution {
+	/// Creates a resolution with the same value in both directions.
+	pub fn square(value: c_int, units: ResolutionUnits) -> Self {
+		Self {
+			cross_feed: value,
+			feed: value,
+			units,
+		}
+	}
+}
+impl Display for Resolution {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "{}x{}{}", self.cross_feed, self.feed, self.units)
+	}
+}
+impl FromStr for Resolution {
+	type Err = ParseResolutionError;
+	/// Parses a resolution such as `600dpi`, `1200x600dpi` or `300dpcm`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let error = || ParseResolutionError(s.to_owned());
+		let (values, units) = if let Some(values) = s.strip_suffix("dpi") {
+			(values, ResolutionUnits::Dpi)
+		} else if let Some(values) = s.strip_suffix("dpcm") {
+			(values, ResolutionUnits::Dpcm)
+		} else {
+			return Err(error());
+		};
+		let parse = |value: &str| value.parse::<c_int>().ok().filter(|value| *value > 0);
+		let (cross_feed, feed) = match values.split_once('x') {
+			Some((cross_feed, feed)) => (parse(cross_feed), parse(feed)),
+			None => (parse(values), parse(values)),
+		};
+		Ok(Self {
+			cross_feed: cross_feed.ok_or_else(error)?,
+			feed: feed.ok_or_else(error)?,
+			units,
+		})
+	}
+}
+impl_print_option!(Resolution => "Resolution");
+impl FromIpp for Resolution {
+	fn from_ipp(value: &str) -> Option<Self> {
+		value.parse().ok()
+	}
+}
+impl_try_from_ipp!(Resolution);
 
-#[derive(Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantNames)]
+/// An error indicating that a string is not a valid [`Resolution`].
+#[derive(Debug, thiserror::Error)]
+#[error("invalid resolution (expected a value such as 600dpi or 1200x600dpi): {0}")]
+pub struct ParseResolutionError(String);
+
+/// The units of a [`Resolution`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum ResolutionUnits {
+	/// Dots per inch.
+	Dpi,
+	/// Dots per centimeter.
+	Dpcm,
+}
+
+#[derive(Clone, Copy, Debug, strum::Display, strum::EnumIter, strum::VariantNames)]
+#[strum(serialize_all = "kebab-case")]
+pub enum RenderingIntent {
+	Auto,
+	/// Preserves the relationships between colors, compressing the whole gamut.
+	Perceptual,
+	/// Preserves colors within the gamut, clipping colors outside of it.
+	Relative,
+	/// Like [`RenderingIntent::Relative`], with black point compensation.
+	RelativeBpc,
+	/// Preserves the saturation of colors.
+	Saturation,
+	/// Preserves colors exactly, including the white point.
+	Absolute,
+}
+impl_print_option!(RenderingIntent => "Rendering Intent", |value| match value {
+	RenderingIntent::Auto => Vec::new(),
+	RenderingIntent::RelativeBpc => vec![RenderingIntent::Relative, RenderingIntent::Auto],
+	_ => vec![RenderingIntent::Auto],
+});
+impl PrintOptionVariants for RenderingIntent {}
+impl_from_str_by_name!(RenderingIntent);
+impl_from_ipp!(RenderingIntent {
+	"auto" => RenderingIntent::Auto,
+	"perceptual" => RenderingIntent::Perceptual,
+	"relative" => RenderingIntent::Relative,
+	"relative-bpc" => RenderingIntent::RelativeBpc,
+	"saturation" => RenderingIntent::Saturation,
+	"absolute" => RenderingIntent::Absolute,
+});
+
+#[derive(Clone, Copy, Debug, strum::Display, strum::EnumIter, strum::VariantNames)]
 #[strum(serialize_all = "kebab-case")]
 pub enum SidesMode {
 	OneSided,
 	TwoSidedPortrait,
 	TwoSidedLandscape,
 }
-impl_print_option!(SidesMode => "SidesMode");
+impl_print_option!(SidesMode => "SidesMode", |value| match value {
+	SidesMode::OneSided => Vec::new(),
+	SidesMode::TwoSidedPortrait | SidesMode::TwoSidedLandscape => vec![SidesMode::OneSided],
+});
 impl PrintOptionVariants for SidesMode {}
+impl_from_str_by_name!(SidesMode);
+impl_from_ipp!(SidesMode {
+	"one-sided" => SidesMode::OneSided,
+	"two-sided-long-edge" => SidesMode::TwoSidedPortrait,
+	"two-sided-short-edge" => SidesMode::TwoSidedLandscape,
+});
+
+#[derive(Clone, Copy, Debug, strum::Display, strum::EnumIter, strum::VariantNames)]
+#[strum(serialize_all = "kebab-case")]
+pub enum OutputOrder {
+	/// Pages are output in order, first page first.
+	Normal,
+	/// Pages are output in reverse order, last page first.
+	Reverse,
+}
+impl_print_option!(OutputOrder => "Output Order");
+impl PrintOptionVariants for OutputOrder {}
+impl_from_str_by_name!(OutputOrder);
+impl_from_ipp!(OutputOrder {
+	"normal" => OutputOrder::Normal,
+	"reverse" => OutputOrder::Reverse,
+});
+
+/// The output bin the printed pages are delivered to, as an IPP `output-bin` keyword.
+///
+/// The bins a printer supports are listed in its `output-bin-supported` attribute.
+/// Bins without a variant (for example, vendor-specific ones) can be specified with
+/// [`OutputBin::Other`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OutputBin {
+	Auto,
+	Top,
+	Middle,
+	Bottom,
+	Side,
+	Left,
+	Right,
+	Center,
+	Rear,
+	FaceUp,
+	FaceDown,
+	LargeCapacity,
+	MyMailbox,
+	/// A numbered tray (`tray-1`, `tray-2`, ...).
+	Tray(u32),
+	/// A numbered stacker (`stacker-1`, `stacker-2`, ...).
+	Stacker(u32),
+	/// A numbered mailbox (`mailbox-1`, `mailbox-2`, ...).
+	Mailbox(u32),
+	/// Any other bin, specified by its keyword.
+	Other(String),
+}
+impl OutputBin {
+	/// The bins that are specified by a fixed keyword.
+	const NAMED: [OutputBin; 13] = [
+		Self::Auto,
+		Self::Top,
+		Self::Middle,
+		Self::Bottom,
+		Self::Side,
+		Self::Left,
+		Self::Right,
+		Self::Center,
+		Self::Rear,
+		Self::FaceUp,
+		Self::FaceDown,
+		Self::LargeCapacity,
+		Self::MyMailbox,
+	];
+	/// Returns the fixed keyword of this bin, or `None` if it is numbered or custom.
+	fn get_keyword(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::Auto => "auto",
+			Self::Top => "top",
+			Self::Middle => "middle",
+			Self::Bottom => "bottom",
+			Self::Side => "side",
+			Self::Left => "left",
+			Self::Right => "right",
+			Self::Center => "center",
+			Self::Rear => "rear",
+			Self::FaceUp => "face-up",
+			Self::FaceDown => "face-down",
+			Self::LargeCapacity => "large-capacity",
+			Self::MyMailbox => "my-mailbox",
+			Self::Tray(_) | Self::Stacker(_) | Self::Mailbox(_) | Self::Other(_) => return None,
+		})
+	}
+}
+impl Display for OutputBin {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Tray(number) => write!(f, "tray-{number}"),
+			Self::Stacker(number) => write!(f, "stacker-{number}"),
+			Self::Mailbox(number) => write!(f, "mailbox-{number}"),
+			Self::Other(keyword) => f.write_str(keyword),
+			named => f.write_str(named.get_keyword().unwrap_or_default()),
+		}
+	}
+}
+impl FromStr for OutputBin {
+	type Err = Infallible;
+	/// Parses an `output-bin` keyword. Unknown keywords are parsed into [`OutputBin::Other`].
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let named = Self::NAMED
+			.into_iter()
+			.find(|bin| bin.get_keyword() == Some(s));
+		if let Some(named) = named {
+			return Ok(named);
+		}
+		let numbered = |prefix: &str| s.strip_prefix(prefix)?.parse::<u32>().ok();
+		if let Some(number) = numbered("tray-") {
+			return Ok(Self::Tray(number));
+		}
+		if let Some(number) = numbered("stacker-") {
+			return Ok(Self::Stacker(number));
+		}
+		if let Some(number) = numbered("mailbox-") {
+			return Ok(Self::Mailbox(number));
+		}
+		Ok(Self::Other(s.to_owned()))
+	}
+}
+impl strum::VariantNames for OutputBin {
+	const VARIANTS: &'static [&'static str] = &[
+		"auto",
+		"top",
+		"middle",
+		"bottom",
+		"side",
+		"left",
+		"right",
+		"center",
+		"rear",
+		"face-up",
+		"face-down",
+		"large-capacity",
+		"my-mailbox",
+	];
+}
+impl_print_option!(OutputBin => "Output Bin", |value| match value {
+	OutputBin::Auto => Vec::new(),
+	_ => vec![OutputBin::Auto],
+});
+impl PrintOptionVariants for OutputBin {}
+
+/// Determines until when the documents of a completed job are retained.
+#[derive(Clone, Copy, Debug, strum::Display, strum::EnumIter, strum::VariantNames)]
+#[strum(serialize_all = "kebab-case")]
+pub enum JobRetention {
+	/// Until the job is deleted.
+	Indefinite,
+	/// Until the next day time period (typically 6am to 6pm).
+	DayTime,
+	/// Until the next evening period (typically 6pm to 6am).
+	Evening,
+	/// Until the next night period (typically midnight to 6am).
+	Night,
+	/// Until the next weekend.
+	Weekend,
+	/// Until the next second shift (typically 4pm to midnight).
+	SecondShift,
+	/// Until the next third shift (typically midnight to 8am).
+	ThirdShift,
+}
+impl_print_option!(JobRetention => "Retention", |value| match value {
+	JobRetention::Indefinite => Vec::new(),
+	_ => vec![JobRetention::Indefinite],
+});
+impl FromIpp for OutputBin {
+	fn from_ipp(value: &str) -> Option<Self> {
+		value.parse().ok()
+	}
+}
+impl_try_from_ipp!(OutputBin);
+impl PrintOptionVariants for JobRetention {}
+impl_from_str_by_name!(JobRetention);
+impl_from_ipp!(JobRetention {
+	"indefinite" => JobRetention::Indefinite,
+	"day-time" => JobRetention::DayTime,
+	"evening" => JobRetention::Evening,
+	"night" => JobRetention::Night,
+	"weekend" => JobRetention::Weekend,
+	"second-shift" => JobRetention::SecondShift,
+	"third-shift" => JobRetention::ThirdShift,
+});
+
+/// The priority of a job, from 1 (lowest) to 100 (highest); other values are rejected.
+/// Jobs with a higher priority are printed before queued jobs with a lower priority.
+#[derive(Clone, Copy, Debug)]
+pub struct JobPriorityInt(pub c_int);
+impl From<c_int> for JobPriorityInt {
+	fn from(value: c_int) -> Self {
+		Self(value)
+	}
+}
+impl PrintOption for JobPriorityInt {
+	fn get_name() -> &'static str {
+		"Job Priority"
+	}
+	fn to_human_string(&self) -> String {
+		self.0.to_string()
+	}
+	fn check_value(&self) -> Result<(), &'static str> {
+		if !(1..=100).contains(&self.0) {
+			return Err("must be between 1 and 100");
+		}
+		Ok(())
+	}
+}
+impl FromIpp for JobPriorityInt {
+	fn from_ipp(value: &str) -> Option<Self> {
+		value
+			.parse()
+			.ok()
+			.map(Self)
+			.filter(|priority: &Self| priority.check_value().is_ok())
+	}
+}
+impl_try_from_ipp!(JobPriorityInt);
+
+/// A banner page printed before or after the documents of a job.
+#[derive(
+	Clone, Copy, Debug, PartialEq, Eq, strum::Display, strum::EnumString, strum::VariantNames,
+)]
+#[strum(serialize_all = "kebab-case")]
+pub enum JobSheet {
+	/// No banner page.
+	None,
+	/// A banner page with the job's title, owner, and similar information.
+	Standard,
+	/// A banner page marked as classified.
+	Classified,
+	/// A banner page marked as confidential.
+	Confidential,
+	/// A banner page marked as secret.
+	Secret,
+	/// A banner page marked as top secret.
+	#[strum(serialize = "topsecret")]
+	TopSecret,
+	/// A banner page marked as unclassified.
+	Unclassified,
+}
+
+/// The banner pages printed at the start and at the end of a job.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct JobSheets {
+	/// The banner page printed before the documents.
+	pub start: JobSheet,
+	/// The banner page printed after the documents. If `None`, the printer's default is used.
+	pub end: Option<JobSheet>,
+}
+impl Display for JobSheets {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self.end {
+			Some(end) => write!(f, "{},{}", self.start, end),
+			None => write!(f, "{}", self.start),
+		}
+	}
+}
+impl FromStr for JobSheets {
+	type Err = strum::ParseError;
+	/// Parses banner pages from a string, such as `standard` or `standard,none`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (start, end) = match s.split_once(',') {
+			Some((start, end)) => (start, Some(end.parse()?)),
+			None => (s, None),
+		};
+		Ok(Self {
+			start: start.parse()?,
+			end,
+		})
+	}
+}
+impl_print_option!(JobSheets => "Job Sheets", |value| match value.start {
+	JobSheet::None => Vec::new(),
+	_ => vec![JobSheets {
+		start: JobSheet::None,
+		end: Some(JobSheet::None),
+	}],
+});
+
+/// The ID of the account the job is charged to, as used by accounting systems.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JobAccountId(pub String);
+impl From<&str> for JobAccountId {
+	fn from(value: &str) -> Self {
+		Self(value.to_owned())
+	}
+}
+impl FromIpp for JobSheets {
+	fn from_ipp(value: &str) -> Option<Self> {
+		value.parse().ok()
+	}
+}
+impl_try_from_ipp!(JobSheets);
+impl PrintOption for JobAccountId {
+	fn get_name() -> &'static str {
+		"Job Account ID"
+	}
+	fn to_human_string(&self) -> String {
+		self.0.clone()
+	}
+}
+impl FromIpp for JobAccountId {
+	fn from_ipp(value: &str) -> Option<Self> {
+		Some(Self::from(value))
+	}
+}
+
+/// The ID of the user the job is accounted to, as used by accounting systems.
+/// This can differ from the user submitting the job.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JobAccountingUserId(pub String);
+impl From<&str> for JobAccountingUserId {
+	fn from(value: &str) -> Self {
+		Self(value.to_owned())
+	}
+}
+impl PrintOption for JobAccountingUserId {
+	fn get_name() -> &'static str {
+		"Job Accounting User ID"
+	}
+	fn to_human_string(&self) -> String {
+		self.0.clone()
+	}
+}
+impl FromIpp for JobAccountingUserId {
+	fn from_ipp(value: &str) -> Option<Self> {
+		Some(Self::from(value))
+	}
+}
+
+/// A compression method applied to documents while they are uploaded.
+#[derive(
+	Clone, Copy, Debug, PartialEq, Eq, strum::Display, strum::EnumIter, strum::VariantNames,
+)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Compression {
+	/// The documents are uploaded uncompressed.
+	None,
+	/// The documents are compressed with gzip.
+	Gzip,
+}
+impl_print_option!(Compression => "Compression", |value| match value {
+	Compression::None => Vec::new(),
+	Compression::Gzip => vec![Compression::None],
+});
+impl PrintOptionVariants for Compression {}
+impl_from_str_by_name!(Compression);
+impl_from_ipp!(Compression {
+	"none" => Compression::None,
+	"gzip" => Compression::Gzip,
+});
+
+/// A password (PIN) that has to be entered at the printer before the job is printed.
+///
+/// Only unencrypted passwords (a `job-password-encryption` of `none`) are supported, and the
+/// password must not be longer than the printer allows. It is masked in [`Debug`] output and
+/// option reports, and is left out of option strings.
+/// Use [`crate::get_capabilities()`] to check whether a printer supports job passwords.
+#[derive(Clone, PartialEq, Eq)]
+pub struct JobPassword(String);
+impl JobPassword {
+	/// Creates a job password from the specified string.
+	pub fn new(password: &str) -> Self {
+		Self(password.to_owned())
+	}
+	/// Returns the password in plain text.
+	pub fn expose(&self) -> &str {
+		&self.0
+	}
+}
+impl fmt::Debug for JobPassword {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str("JobPassword(****)")
+	}
+}
+impl PrintOption for JobPassword {
+	fn get_name() -> &'static str {
+		"Job Password"
+	}
+	fn to_human_string(&self) -> String {
+		String::from("****")
+	}
+}
+impl FromIpp for JobPassword {
+	fn from_ipp(value: &str) -> Option<Self> {
+		Some(Self::new(value))
+	}
+}
+impl_try_from_ipp!(JobPassword);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn if_number_up_unsupported_then_fallbacks_descend_to_one() {
+		// Four pages per side:
+		let number_up = NumberUpInt(4);
+
+		// Fallbacks should be fewer pages per side, closest first:
+		let fallbacks = number_up
+			.get_fallbacks()
+			.iter()
+			.map(|fallback| fallback.0)
+			.collect::<Vec<_>>();
+		assert_eq!(vec![3, 2, 1], fallbacks);
+	}
+
+	#[test]
+	fn if_high_quality_unsupported_then_first_fallback_is_normal() {
+		// High quality:
+		let quality = Quality::High;
+
+		// The closest fallback should be normal quality:
+		let fallbacks = quality.get_fallbacks();
+		assert!(
+			matches!(fallbacks.first(), Some(Quality::Normal)),
+			"First fallback should be normal quality, was: {:?}",
+			fallbacks.first()
+		);
+	}
+
+	#[test]
+	fn if_monochrome_unsupported_then_fallbacks_are_monochrome() {
+		// Monochrome output:
+		let color_mode = ColorMode::Monochrome;
+
+		// Fallbacks should not allow printing in color:
+		let fallbacks = color_mode.get_fallbacks();
+		assert!(
+			!fallbacks.is_empty()
+				&& fallbacks.iter().all(|fallback| matches!(
+					fallback,
+					ColorMode::AutoMonochrome | ColorMode::ProcessMonochrome
+				)),
+			"Fallbacks should only contain monochrome modes, were: {fallbacks:?}"
+		);
+	}
+
+	#[test]
+	fn if_output_bin_unsupported_then_fallback_is_auto() {
+		// A specific output bin:
+		let output_bin = OutputBin::FaceDown;
+
+		// The only fallback should be automatic selection:
+		let fallbacks = output_bin.get_fallbacks();
+		assert!(
+			matches!(fallbacks.as_slice(), [OutputBin::Auto]),
+			"Fallbacks should only contain auto, were: {fallbacks:?}"
+		);
+	}
+
+	#[test]
+	fn if_media_source_is_numbered_or_custom_then_keyword_is_kept() {
+		// Named, numbered and custom sources:
+		let keywords = ["by-pass-tray", "tray-2", "roll-1", "vendor-drawer"];
+		let sources = keywords.map(|keyword| MediaSource::from_str(keyword).unwrap());
+
+		assert_eq!(
+			sources,
+			[
+				MediaSource::ByPassTray,
+				MediaSource::Tray(2),
+				MediaSource::Roll(1),
+				MediaSource::Other("vendor-drawer".to_owned()),
+			],
+			"Keywords should be parsed into the corresponding sources"
+		);
+		// Displaying a source should result in its keyword:
+		assert_eq!(
+			keywords,
+			sources.map(|source| source.to_string()),
+			"Sources should be displayed as their keywords"
+		);
+	}
+
+	#[test]
+	fn if_output_bin_is_numbered_or_custom_then_keyword_is_kept() {
+		// Named, numbered and custom bins:
+		let keywords = [
+			"face-up",
+			"tray-2",
+			"stacker-1",
+			"mailbox-3",
+			"vendor-sorter",
+		];
+		let bins = keywords.map(|keyword| OutputBin::from_str(keyword).unwrap());
+
+		assert_eq!(
+			bins,
+			[
+				OutputBin::FaceUp,
+				OutputBin::Tray(2),
+				OutputBin::Stacker(1),
+				OutputBin::Mailbox(3),
+				OutputBin::Other("vendor-sorter".to_owned()),
+			],
+			"Keywords should be parsed into the corresponding bins"
+		);
+		// Displaying a bin should result in its keyword:
+		assert_eq!(
+			keywords,
+			bins.map(|bin| bin.to_string()),
+			"Bins should be displayed as their keywords"
+		);
+	}
+
+	#[test]
+	fn if_resolution_has_optional_feed_then_it_is_parsed() {
+		// Square and non-square resolutions:
+		let square = Resolution::from_str("600dpi").expect("Could not parse resolution");
+		let non_square = Resolution::from_str("1200x600dpcm").expect("Could not parse resolution");
+
+		assert_eq!(
+			square,
+			Resolution::square(600, ResolutionUnits::Dpi),
+			"A single value should be used for both directions"
+		);
+		assert_eq!(
+			non_square.to_string(),
+			"1200x600dpcm",
+			"A non-square resolution should be displayed with both values"
+		);
+		// A resolution without units:
+		assert!(
+			Resolution::from_str("600").is_err(),
+			"A resolution without units should be rejected"
+		);
+	}
+
+	#[test]
+	fn if_job_sheets_have_optional_end_then_both_are_parsed() {
+		// Only the start sheet:
+		let start_only = "standard"
+			.parse::<JobSheets>()
+			.expect("Could not parse job sheets");
+		assert_eq!(
+			start_only.start,
+			JobSheet::Standard,
+			"Start sheet should be parsed"
+		);
+		assert_eq!(start_only.end, None, "End sheet should not be set");
+
+		// Start and end sheets:
+		let both = "classified,topsecret"
+			.parse::<JobSheets>()
+			.expect("Could not parse job sheets");
+		assert_eq!(
+			both.start,
+			JobSheet::Classified,
+			"Start sheet should be parsed"
+		);
+		assert_eq!(
+			both.end,
+			Some(JobSheet::TopSecret),
+			"End sheet should be parsed"
+		);
+		assert_eq!(
+			both.to_string(),
+			"classified,topsecret",
+			"Job sheets should be displayed as their keywords"
+		);
+
+		// An unknown end sheet:
+		assert!(
+			"standard,bogus".parse::<JobSheets>().is_err(),
+			"Unknown sheets should not be parsed"
+		);
+	}
+
+	#[test]
+	fn if_job_priority_out_of_range_then_value_is_invalid() {
+		// Priorities below 1 and above 100:
+		for priority in [0, -5, 101] {
+			assert!(
+				JobPriorityInt(priority).check_value().is_err(),
+				"Priority {priority} should be invalid, but wasn't"
+			);
+		}
+		// The lowest and highest priorities:
+		for priority in [1, 100] {
+			assert!(
+				JobPriorityInt(priority).check_value().is_ok(),
+				"Priority {priority} should be valid, but wasn't"
+			);
+		}
+	}
+
+	#[test]
+	fn if_job_password_is_set_then_debug_output_is_masked() {
+		// Options with a password:
+		let options = PrintOptions {
+			job_password: Some(JobPassword::new("4711")),
+			..Default::default()
+		};
+
+		// The password should not appear in the debug output, but should still be available:
+		let debug = format!("{options:?}");
+		assert!(!debug.contains("4711"), "Password was not masked: {debug}");
+		assert_eq!(
+			options.job_password.unwrap().expose(),
+			"4711",
+			"Password should be exposed unchanged"
+		);
+	}
+
+	#[test]
+	#[allow(clippy::reversed_empty_ranges)]
+	fn if_page_ranges_are_empty_or_reversed_then_value_is_invalid() {
+		// No ranges, a range starting at page 0, and a range ending before it starts:
+		let invalid = [vec![], vec![0..=2], vec![1..=2, 5..=3]];
+		for ranges in invalid {
+			assert!(
+				ranges.check_value().is_err(),
+				"Page ranges should be invalid, but weren't: {ranges:?}"
+			);
+		}
+
+		// Single pages and ranges of several pages:
+		let valid = vec![1..=1, 3..=5];
+		assert!(
+			valid.check_value().is_ok(),
+			"Page ranges should be valid, but weren't: {valid:?}"
+		);
+	}
+
+	#[test]
+	fn if_copies_is_zero_then_builder_returns_err() {
+		let result = PrintOptions::builder()
+			.media_size(MediaSize::A4)
+			.copies(0)
+			.build();
+		assert!(
+			matches!(result, Err(PrintError::InvalidOption { ref name, .. }) if name == "copies"),
+			"Builder should reject zero copies, but returned: {result:?}"
+		);
+	}
+
+	#[test]
+	fn if_later_layer_disables_borderless_then_merged_options_are_not_borderless() {
+		// A preset enables borderless printing, and the per-call options disable it:
+		let preset = PrintOptions::builder()
+			.borderless(true)
+			.build()
+			.expect("Options should be valid");
+		let overrides = PrintOptions::builder()
+			.borderless(false)
+			.build()
+			.expect("Options should be valid");
+
+		let options = preset.merge(overrides);
+		assert_eq!(
+			options.borderless,
+			Some(false),
+			"Borderless printing should be disabled by the later layer"
+		);
+	}
+
+	#[test]
+	fn if_options_are_merged_then_later_layers_take_precedence() {
+		// Defaults, a preset and per-call options, which all set the amount of copies:
+		let defaults = PrintOptions::builder()
+			.copies(1)
+			.media_size(MediaSize::A4)
+			.build()
+			.expect("Options should be valid");
+		let preset = PrintOptions::builder()
+			.sides_mode(SidesMode::TwoSidedPortrait)
+			.copies(2)
+			.build()
+			.expect("Options should be valid");
+		let overrides = PrintOptions::builder()
+			.copies(3)
+			.build()
+			.expect("Options should be valid");
+
+		// The last layer should win, and options set in earlier layers only should be kept:
+		let options = defaults.merge(preset).merge(overrides);
+		assert_eq!(
+			options.copies.map(|copies| copies.0),
+			Some(3),
+			"Copies should be taken from the last layer"
+		);
+		assert!(
+			matches!(options.media_size, Some(MediaSize::A4)),
+			"Media size should be kept from the defaults, was: {:?}",
+			options.media_size
+		);
+		assert!(
+			matches!(options.sides_mode, Some(SidesMode::TwoSidedPortrait)),
+			"Sides mode should be kept from the preset, was: {:?}",
+			options.sides_mode
+		);
+	}
+
+	#[test]
+	fn if_ipp_values_are_converted_then_options_match() {
+		// IPP keywords and enum values:
+		assert!(
+			matches!(MediaSize::from_ipp("iso_a4_210x297mm"), Some(MediaSize::A4)),
+			"IPP media keyword should be converted to A4, but wasn't"
+		);
+		assert!(
+			matches!(Finishing::from_ipp_enum(4), Some(Finishing::Staple)),
+			"IPP finishing 4 should be converted to staple, but wasn't"
+		);
+		assert!(
+			Finishing::from_ipp_enum(1000).is_none(),
+			"Unknown IPP finishing should not be converted, but was"
+		);
+		assert!(
+			matches!(Orientation::from_ipp_enum(4), Some(Orientation::Landscape)),
+			"IPP orientation 4 should be converted to landscape, but wasn't"
+		);
+		assert!(
+			matches!(Quality::from_ipp_enum(5), Some(Quality::High)),
+			"IPP quality 5 should be converted to high, but wasn't"
+		);
+		assert!(
+			Quality::from_ipp_enum(1).is_none(),
+			"Unknown IPP quality should not be converted, but was"
+		);
+	}
+
+	#[test]
+	fn if_ipp_values_are_converted_with_try_from_then_options_match() {
+		// An enum keyword, an enum value, and an integer:
+		assert!(
+			matches!(MediaSize::try_from("iso_a4_210x297mm"), Ok(MediaSize::A4)),
+			"IPP media keyword should be converted to A4, but wasn't"
+		);
+		assert!(
+			matches!(Orientation::try_from("4"), Ok(Orientation::Landscape)),
+			"IPP orientation 4 should be converted to landscape, but wasn't"
+		);
+		assert_eq!(
+			CopiesInt::try_from("3").map(|copies| copies.0).ok(),
+			Some(3),
+			"IPP integer should be converted to copies, but wasn't"
+		);
+		assert!(
+			CopiesInt::try_from("three").is_err(),
+			"Non-integer should not be converted to copies, but was"
+		);
+		// Lists are converted with `try_from_ipp()`:
+		assert!(
+			matches!(
+				Vec::<Finishing>::try_from_ipp("4,5").as_deref(),
+				Ok([Finishing::Staple, Finishing::Punch])
+			),
+			"IPP finishings should be converted to a list, but weren't"
+		);
+	}
+
+	#[test]
+	fn if_variant_names_are_parsed_then_from_str_is_exact_and_ipp_ignores_case() {
+		// A variant name, in its displayed form and in upper case:
+		let parsed = MediaSize::from_str("a4");
+		let parsed_upper = MediaSize::from_str("A4");
+		let converted_upper = MediaSize::try_from("A4");
+
+		assert!(
+			matches!(parsed, Ok(MediaSize::A4)),
+			"Variant name should be parsed, was: {parsed:?}"
+		);
+		assert!(
+			parsed_upper.is_err(),
+			"Variant name in upper case should not be parsed, was: {parsed_upper:?}"
+		);
+		assert!(
+			matches!(converted_upper, Ok(MediaSize::A4)),
+			"Variant name in upper case should be converted as an IPP value, was: {converted_upper:?}"
+		);
+	}
+}
@@ -0,0 +1,768 @@
+use std::str::FromStr;
+
+use crate::print::Printer;
+
+// MARK: - State
+
+impl Printer {
+	/// Returns the current state of this printer, if known.
+	pub fn get_state(&self) -> Option<PrinterState> {
+		PrinterState::from_ipp(self.get_option("printer-state")?)
+	}
+	/// Returns the reasons for the current state of this printer.
+	/// If there are no reasons, or they are not known, returns an empty vector.
+	pub fn get_state_reasons(&self) -> Vec<StateReason> {
+		self.get_option("printer-state-reasons")
+			.map(|value| parse_state_reasons(value))
+			.unwrap_or_default()
+	}
+}
+
+/// The state of a printer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "kebab-case")]
+pub enum PrinterState {
+	/// The printer is ready to accept and process jobs.
+	Idle,
+	/// The printer is processing a job.
+	Processing,
+	/// The printer cannot process jobs, and intervention is required.
+	Stopped,
+}
+impl PrinterState {
+	/// Maps a value of the IPP `printer-state` attribute to a printer state.
+	pub(crate) fn from_ipp(value: &str) -> Option<Self> {
+		match value {
+			"3" => Some(Self::Idle),
+			"4" => Some(Self::Processing),
+			"5" => Some(Self::Stopped),
+			_ => None,
+		}
+	}
+}
+
+/// A reason for the current state of a printer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateReason {
+	/// The condition the printer is in.
+	pub keyword: StateReasonKeyword,
+	/// The severity of the condition.
+	pub severity: Severity,
+}
+impl StateReason {
+	/// Parses a single value of the `printer-state-reasons` attribute, such as `toner-low-warning`.
+	/// Values without a severity suffix are errors.
+	pub fn parse(value: &str) -> Self {
+		let (keyword, severity) = [
+			("-report", Severity::Report),
+			("-warning", Severity::Warning),
+			("-error", Severity::Error),
+		]
+		.into_iter()
+		.find_map(|(suffix, severity)| Some((value.strip_suffix(suffix)?, severity)))
+		.unwrap_or((value, Severity::Error));
+		// Unknown keywords are parsed into `StateReasonKeyword::Other`:
+		let keyword = StateReasonKeyword::from_str(keyword)
+			.unwrap_or_else(|_| StateReasonKeyword::Other(keyword.to_owned()));
+		Self { keyword, severity }
+	}
+}
+
+/// The condition described by a printer state reason.
+///
+/// The values are the standard IPP keywords; any other keyword (for example, vendor-specific ones)
+/// is represented by [`StateReasonKeyword::Other`].
+#[derive(Clone, Debug, PartialEq, Eq, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum StateReasonKeyword {
+	ConnectingToDevice,
+	CoverOpen,
+	DeveloperEmpty,
+	DeveloperLow,
+	DoorOpen,
+	FuserOverTemp,
+	FuserUnderTemp,
+	InputTrayMissing,
+	InterlockOpen,
+	InterpreterResourceUnavailable,
+	MarkerSupplyEmpty,
+	MarkerSupplyLow,
+	MarkerWasteAlmostFull,
+	MarkerWasteFull,
+	MediaEmpty,
+	MediaJam,
+	MediaLow,
+	MediaNeeded,
+	MovingToPaused,
+	Offline,
+	OpcLifeOver,
+	OpcNearEol,
+	OutputAreaAlmostFull,
+	OutputAreaFull,
+	OutputTrayMissing,
+	Paused,
+	Shutdown,
+	SpoolAreaFull,
+	StoppedPartly,
+	Stopping,
+	TimedOut,
+	TonerEmpty,
+	TonerLow,
+	#[strum(default)]
+	Other(String),
+}
+
+/// The severity of a printer state reason.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Severity {
+	/// An informational condition.
+	Report,
+	/// A condition that does not prevent printing, but may need attention (for example, low toner).
+	Warning,
+	/// A condition that prevents printing.
+	Error,
+}
+
+/// Parses the comma-separated value of the `printer-state-reasons` attribute.
+/// The keyword `none` results in an empty vector.
+fn parse_state_reasons(value: &str) -> Vec<StateReason> {
+	value
+		.split(',')
+		.map(str::trim)
+		.filter(|reason| !reason.is_empty() && *reason != "none")
+		.map(StateReason::parse)
+		.collect()
+}
+
+// MARK: - Supplies
+
+impl Printer {
+	/// Returns the supplies (markers) of this printer, such as toner or ink cartridges,
+	/// one entry per marker.
+	/// If the printer does not report its supplies, returns an empty vector.
+	pub fn get_supplies(&self) -> Vec<Supply> {
+		let names = self.get_list_option("marker-names");
+		let colors = self.get_list_option("marker-colors");
+		let types = self.get_list_option("marker-types");
+		let levels = self.get_list_option("marker-levels");
+		let low_levels = self.get_list_option("marker-low-levels");
+		let high_levels = self.get_list_option("marker-high-levels");
+
+		names
+			.iter()
+			.enumerate()
+			.map(|(i, name)| Supply {
+				name: name.to_string(),
+				color: colors
+					.get(i)
+					.filter(|color| !color.is_empty() && *color != "none")
+					.cloned(),
+				supply_type: types.get(i).map(|value| SupplyType::parse(value)),
+				level: levels
+					.get(i)
+					.and_then(|value| value.parse::<i32>().ok())
+					.map(SupplyLevel::from)
+					.unwrap_or(SupplyLevel::Unknown),
+				low_level: low_levels.get(i).and_then(|value| value.parse().ok()),
+				high_level: high_levels.get(i).and_then(|value| value.parse().ok()),
+			})
+			.collect()
+	}
+	/// Returns the supplies of this printer whose level is at or below their low threshold.
+	pub fn get_low_supplies(&self) -> Vec<Supply> {
+		self.get_supplies()
+			.into_iter()
+			.filter(|supply| supply.is_low())
+			.collect()
+	}
+	/// Returns the values of a comma-separated option, or an empty vector if it is not present.
+	pub(crate) fn get_list_option(&self, name: &str) -> Vec<String> {
+		self.get_option(name)
+			.map(|value| split_list_value(value))
+			.unwrap_or_default()
+	}
+}
+
+/// A supply (marker) of a printer, such as a toner or an ink cartridge.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Supply {
+	/// The name of the supply, as reported by the printer.
+	pub name: String,
+	/// The color of the supply as a `#RRGGBB` string, if applicable.
+	/// Supplies containing several colors have several `#RRGGBB` values, such as `#00FFFF#FF00FF`.
+	pub color: Option<String>,
+	/// The type of the supply.
+	pub supply_type: Option<SupplyType>,
+	/// The current level of the supply.
+	pub level: SupplyLevel,
+	/// The level at or below which the supply is considered low.
+	pub low_level: Option<u32>,
+	/// The level at which the supply is considered full (usually 100).
+	pub high_level: Option<u32>,
+}
+impl Supply {
+	/// Returns `true` if the level of this supply is known, and is at or below its low threshold.
+	/// If no low threshold is reported, only an empty supply is considered low.
+	///
+	/// Waste supplies (such as waste toner) fill up instead, and are considered low (that is,
+	/// in need of replacement) if their level is at or above their high threshold.
+	pub fn is_low(&self) -> bool {
+		let SupplyLevel::Known(level) = self.level else {
+			return false;
+		};
+		if self.supply_type.as_ref().is_some_and(SupplyType::is_waste) {
+			level >= self.high_level.unwrap_or(100)
+		} else {
+			level <= self.low_level.unwrap_or(0)
+		}
+	}
+	/// Returns the level of this supply as a percentage between its low threshold (0%) and its
+	/// high threshold (100%), if the level is known. Without reported thresholds, these are 0
+	/// and 100.
+	pub fn get_percentage(&self) -> Option<u8> {
+		let SupplyLevel::Known(level) = self.level else {
+			return None;
+		};
+		let low = self.low_level.unwrap_or(0);
+		let high = self.high_level.unwrap_or(100);
+		if high <= low {
+			return None;
+		}
+		let percentage = (level as f64 - low as f64) / ((high - low) as f64) * 100.0;
+		Some(percentage.clamp(0.0, 100.0) as u8)
+	}
+}
+
+/// The level of a supply.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SupplyLevel {
+	/// The level is known (usually a percentage).
+	Known(u32),
+	/// The level is unknown, but the supply is not empty.
+	SomeRemaining,
+	/// The level is unknown.
+	Unknown,
+	/// The level cannot be determined for this supply.
+	Unavailable,
+}
+impl From<i32> for SupplyLevel {
+	/// Converts a value of the `marker-levels` attribute, where negative values have special
+	/// meanings.
+	fn from(value: i32) -> Self {
+		match value {
+			0.. => Self::Known(value as u32),
+			-1 => Self::Unavailable,
+			-3 => Self::SomeRemaining,
+			_ => Self::Unknown,
+		}
+	}
+}
+
+/// The type of a supply, as per the IPP `marker-types` keywords.
+///
+/// Any other keyword is represented by [`SupplyType::Other`].
+#[derive(Clone, Debug, PartialEq, Eq, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum SupplyType {
+	BandingSupply,
+	BindingSupply,
+	CleanerUnit,
+	CoronaWire,
+	Covers,
+	Developer,
+	Fuser,
+	FuserCleaningPad,
+	FuserOil,
+	FuserOilWick,
+	FuserOiler,
+	Ink,
+	InkCartridge,
+	InkRibbon,
+	Inserts,
+	Opc,
+	PaperWrap,
+	RibbonWax,
+	ShrinkWrap,
+	SolidWax,
+	Staples,
+	StitchingWire,
+	Toner,
+	TonerCartridge,
+	TransferUnit,
+	WasteInk,
+	WasteToner,
+	WasteWater,
+	WasteWax,
+	Water,
+	#[strum(default)]
+	Other(String),
+}
+impl SupplyType {
+	/// Parses a value of the `marker-types` attribute.
+	fn parse(value: &str) -> Self {
+		Self::from_str(value).unwrap_or_else(|_| Self::Other(value.to_owned()))
+	}
+	/// Returns `true` if supplies of this type collect waste, and thus fill up during printing.
+	pub fn is_waste(&self) -> bool {
+		matches!(
+			self,
+			Self::WasteInk | Self::WasteToner | Self::WasteWater | Self::WasteWax
+		)
+	}
+}
+
+/// Splits the value of a multi-valued option into its values.
+///
+/// Values are separated by commas. Values containing commas may be quoted (with `'` or `"`), or
+/// have their commas escaped with a backslash.
+fn split_list_value(value: &str) -> Vec<String> {
+	let mut values = Vec::new();
+	let mut current = String::new();
+	let mut quote = None;
+	let mut chars = value.chars();
+	while let Some(c) = chars.next() {
+		match (c, quote) {
+			('\\', _) => current.extend(chars.next()),
+			('\'' | '"', None) => quote = Some(c),
+			(c, Some(q)) if c == q => quote = None,
+			(',', None) => values.push(std::mem::take(&mut current).trim().to_owned()),
+			(c, _) => current.push(c),
+		}
+	}
+	values.push(current.trim().to_owned());
+	values
+}
+
+// MARK: - Attributes
+
+impl Printer {
+	/// Returns the location of this printer, such as a room or a floor, if known.
+	pub fn get_location(&self) -> Option<&String> {
+		self.get_option("printer-location")
+			.filter(|value| !value.is_empty())
+	}
+	/// Returns the make and model of this printer, if known.
+	pub fn get_make_and_model(&self) -> Option<&String> {
+		self.get_option("printer-make-and-model")
+			.filter(|value| !value.is_empty())
+	}
+	/// Returns the URI of the device this printer sends jobs to, if known.
+	pub fn get_device_uri(&self) -> Option<Uri> {
+		self.get_option("device-uri")
+			.and_then(|value| Uri::parse(value))
+	}
+	/// Returns the URIs this printer can be reached by.
+	/// If the URIs are not known, returns an empty vector.
+	pub fn get_supported_uris(&self) -> Vec<Uri> {
+		self.get_list_option("printer-uri-supported")
+			.iter()
+			.filter_map(|value| Uri::parse(value))
+			.collect()
+	}
+	/// Returns `true` if this printer is shared with other computers on the network, and `false`
+	/// if it is not. Returns `None` if this is not known.
+	pub fn is_shared(&self) -> Option<bool> {
+		match self.get_option("printer-is-shared")?.as_str() {
+			"true" => Some(true),
+			"false" => Some(false),
+			_ => None,
+		}
+	}
+	/// Returns the capabilities and properties of this printer, as reported by the printer type.
+	pub fn get_printer_type(&self) -> Option<PrinterType> {
+		let bits = self.get_option("printer-type")?.parse::<u32>().ok()?;
+		Some(PrinterType::from_bits_retain(bits))
+	}
+}
+
+bitflags::bitflags! {
+	/// Capabilities and properties of a printer, decoded from the CUPS `printer-type` attribute.
+	#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+	pub struct PrinterType: u32 {
+		/// The printer is a class of printers.
+		const CLASS = 0x0000_0001;
+		/// The printer is hosted on a remote server.
+		const REMOTE = 0x0000_0002;
+		/// The printer can print in black.
+		const BLACK = 0x0000_0004;
+		/// The printer can print in color.
+		const COLOR = 0x0000_0008;
+		/// The printer can print on both sides of the media.
+		const DUPLEX = 0x0000_0010;
+		/// The printer can staple output.
+		const STAPLE = 0x0000_0020;
+		/// The printer can produce copies in hardware.
+		const COPIES = 0x0000_0040;
+		/// The printer can collate copies in hardware.
+		const COLLATE = 0x0000_0080;
+		/// The printer can punch output.
+		const PUNCH = 0x0000_0100;
+		/// The printer can cover output.
+		const COVER = 0x0000_0200;
+		/// The printer can bind output.
+		const BIND = 0x0000_0400;
+		/// The printer can sort output.
+		const SORT = 0x0000_0800;
+		/// The printer can print on media up to US Legal or ISO A4.
+		const SMALL = 0x0000_1000;
+		/// The printer can print on media from US Legal or ISO A4 up to ISO C or A2.
+		const MEDIUM = 0x0000_2000;
+		/// The printer can print on media larger than ISO C or A2.
+		const LARGE = 0x0000_4000;
+		/// The printer can print on media of variable size.
+		const VARIABLE = 0x0000_8000;
+		/// The printer is the default printer.
+		const DEFAULT = 0x0002_0000;
+		/// The printer is a fax queue.
+		const FAX = 0x0004_0000;
+		/// The printer is rejecting jobs.
+		const REJECTING = 0x0008_0000;
+		/// The printer is not shared.
+		const NOT_SHARED = 0x0020_0000;
+		/// The printer requires authentication.
+		const AUTHENTICATED = 0x0040_0000;
+		/// The printer supports maintenance commands.
+		const COMMANDS = 0x0080_0000;
+		/// The printer was discovered automatically.
+		const DISCOVERED = 0x0100_0000;
+		/// The printer is also a scanner.
+		const SCANNER = 0x0200_0000;
+		/// The printer is a multi-function device (printer and scanner).
+		const MFP = 0x0400_0000;
+	}
+}
+
+/// A URI, split into its components.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Uri {
+	/// The scheme, such as `ipp`, `usb` or `socket`.
+	pub scheme: String,
+	/// The host name or address (without brackets for IPv6 addresses), if present.
+	pub host: Option<String>,
+	/// The port, if present.
+	pub port: Option<u16>,
+	/// The path, including the leading slash if present. May be empty.
+	pub path: String,
+	/// The query (the part after `?`), if present.
+	pub query: Option<String>,
+}
+impl Uri {
+	/// Parses a URI of the form `scheme://[user@]host[:port]/path[?query]` or `scheme:path`.
+	/// Returns `None` if the URI has no scheme.
+	pub fn parse(value: &str) -> Option<Self> {
+		let (scheme, rest) = value.split_once(':')?;
+		if scheme.is_empty() {
+			return None;
+		}
+		let (rest, query) = match rest.split_once('?') {
+			Some((rest, query)) => (rest, Some(query.to_owned())),
+			None => (rest, None),
+		};
+
+		let (host, port, path) = match rest.strip_prefix("//") {
+			Some(rest) => {
+				let (authority, path) = rest.find('/').map_or((rest, ""), |i| rest.split_at(i));
+				let (host, port) = split_host_port(authority);
+				(Some(host.to_owned()).filter(|h| !h.is_empty()), port, path)
+			}
+			None => (None, None, rest),
+		};
+
+		Some(Self {
+			scheme: scheme.to_owned(),
+			host,
+			port,
+			path: path.to_owned(),
+			query,
+		})
+	}
+}
+
+/// Splits the authority part of a URI into a host and a port, removing any user information.
+fn split_host_port(authority: &str) -> (&str, Option<u16>) {
+	let host_port = authority
+		.rsplit_once('@')
+		.map_or(authority, |(_, host_port)| host_port);
+	// IPv6 addresses are enclosed in brackets:
+	if let Some(rest) = host_port.strip_prefix('[') {
+		let (host, rest) = rest.split_once(']').unwrap_or((rest, ""));
+		let port = rest.strip_prefix(':').and_then(|port| port.parse().ok());
+		return (host, port);
+	}
+	match host_port.rsplit_once(':') {
+		Some((host, port)) => (host, port.parse().ok()),
+		None => (host_port, None),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::HashMap;
+
+	use super::*;
+
+	#[test]
+	fn if_state_reasons_none_then_empty() {
+		// The printer reports no reasons:
+		let reasons = parse_state_reasons("none");
+
+		// There should be no state reasons:
+		assert!(reasons.is_empty(), "Expected no reasons, got: {reasons:?}");
+	}
+
+	#[test]
+	fn if_state_reason_has_suffix_then_severity_is_parsed() {
+		// Reasons with each of the suffixes:
+		let reasons = parse_state_reasons("toner-low-warning,media-empty-error,paused-report");
+
+		// Keywords and severities should be parsed:
+		assert_eq!(
+			vec![
+				StateReason {
+					keyword: StateReasonKeyword::TonerLow,
+					severity: Severity::Warning
+				},
+				StateReason {
+					keyword: StateReasonKeyword::MediaEmpty,
+					severity: Severity::Error
+				},
+				StateReason {
+					keyword: StateReasonKeyword::Paused,
+					severity: Severity::Report
+				},
+			],
+			reasons,
+			"Expected three reasons with their severities, got: {reasons:?}"
+		);
+	}
+
+	#[test]
+	fn if_state_reason_has_no_suffix_then_severity_is_error() {
+		// A reason without a suffix:
+		let reasons = parse_state_reasons("door-open");
+
+		// The reason should be an error:
+		assert_eq!(
+			vec![StateReason {
+				keyword: StateReasonKeyword::DoorOpen,
+				severity: Severity::Error
+			}],
+			reasons,
+			"Expected one error, got: {reasons:?}"
+		);
+	}
+
+	#[test]
+	fn if_state_reason_is_unknown_then_other() {
+		// A vendor-specific reason:
+		let reasons = parse_state_reasons("com.vendor-ink-smudge-warning");
+
+		// The keyword should be preserved:
+		assert_eq!(
+			vec![StateReason {
+				keyword: StateReasonKeyword::Other("com.vendor-ink-smudge".to_owned()),
+				severity: Severity::Warning
+			}],
+			reasons,
+			"Expected the vendor keyword to be preserved, got: {reasons:?}"
+		);
+	}
+
+	#[test]
+	fn if_many_markers_then_one_supply_per_marker() {
+		// A printer with two cartridges:
+		let printer = printer_with_options(&[
+			("marker-names", "Black Toner,Cyan Toner"),
+			("marker-colors", "#000000,#00FFFF"),
+			("marker-types", "toner-cartridge,toner-cartridge"),
+			("marker-levels", "80,-3"),
+			("marker-low-levels", "10,10"),
+			("marker-high-levels", "100,100"),
+		]);
+
+		// Both supplies should be parsed:
+		let supplies = printer.get_supplies();
+		assert_eq!(
+			2,
+			supplies.len(),
+			"Expected two supplies, got: {supplies:?}"
+		);
+		assert_eq!(
+			"Black Toner", supplies[0].name,
+			"Unexpected name of the first supply"
+		);
+		assert_eq!(
+			Some("#000000".to_owned()),
+			supplies[0].color,
+			"Unexpected color of the first supply"
+		);
+		assert_eq!(
+			Some(SupplyType::TonerCartridge),
+			supplies[0].supply_type,
+			"Unexpected type of the first supply"
+		);
+		assert_eq!(
+			SupplyLevel::Known(80),
+			supplies[0].level,
+			"Unexpected level of the first supply"
+		);
+		assert_eq!(
+			"Cyan Toner", supplies[1].name,
+			"Unexpected name of the second supply"
+		);
+		assert_eq!(
+			SupplyLevel::SomeRemaining,
+			supplies[1].level,
+			"Unexpected level of the second supply"
+		);
+	}
+
+	#[test]
+	fn if_supply_below_low_level_then_low_supplies_contains_it() {
+		// A printer with one low and one full cartridge:
+		let printer = printer_with_options(&[
+			("marker-names", "Black,Color"),
+			("marker-levels", "5,100"),
+			("marker-low-levels", "10,10"),
+		]);
+
+		// Only the low cartridge should be returned:
+		let low = printer.get_low_supplies();
+		assert_eq!(1, low.len(), "Expected one low supply, got: {low:?}");
+		assert_eq!(
+			"Black", low[0].name,
+			"Expected the black cartridge to be low"
+		);
+	}
+
+	#[test]
+	fn if_marker_name_contains_quoted_comma_then_it_is_one_supply() {
+		// A printer whose first marker has a comma in its name:
+		let printer = printer_with_options(&[
+			("marker-names", "'Black, High Yield',\"Cyan\""),
+			("marker-levels", "40,60"),
+		]);
+
+		// The quoted names should not be split:
+		let names = printer
+			.get_supplies()
+			.into_iter()
+			.map(|supply| supply.name)
+			.collect::<Vec<_>>();
+		assert_eq!(
+			vec!["Black, High Yield".to_owned(), "Cyan".to_owned()],
+			names,
+			"Expected two supplies, got: {names:?}"
+		);
+	}
+
+	#[test]
+	fn if_waste_supply_nearly_full_then_it_is_low() {
+		// A printer with a nearly full waste toner box and a full toner cartridge:
+		let printer = printer_with_options(&[
+			("marker-names", "Waste,Black"),
+			("marker-types", "waste-toner,toner-cartridge"),
+			("marker-levels", "95,100"),
+			("marker-low-levels", "10,10"),
+			("marker-high-levels", "90,100"),
+		]);
+
+		// Only the waste toner box should need replacement:
+		let low = printer.get_low_supplies();
+		assert_eq!(1, low.len(), "Expected one low supply, got: {low:?}");
+		assert_eq!(
+			"Waste", low[0].name,
+			"Expected the waste toner box to be low"
+		);
+	}
+
+	#[test]
+	fn if_supply_has_thresholds_then_percentage_is_between_them() {
+		// A cartridge halfway between its thresholds, and one without thresholds:
+		let printer = printer_with_options(&[
+			("marker-names", "Black,Cyan"),
+			("marker-levels", "55,40"),
+			("marker-low-levels", "10"),
+			("marker-high-levels", "100"),
+		]);
+
+		// The percentages should be relative to the thresholds:
+		let percentages = printer
+			.get_supplies()
+			.iter()
+			.map(Supply::get_percentage)
+			.collect::<Vec<_>>();
+		assert_eq!(
+			vec![Some(50), Some(40)],
+			percentages,
+			"Unexpected percentages: {percentages:?}"
+		);
+	}
+
+	#[test]
+	fn if_network_uri_then_host_port_and_path_are_parsed() {
+		// A URI with user info, port and query:
+		let uri = Uri::parse("ipp://user@printserver:631/printers/office?waitjob=false")
+			.expect("URI should be parsed");
+
+		// All parts should be parsed:
+		assert_eq!("ipp", uri.scheme, "Unexpected scheme");
+		assert_eq!(Some("printserver".to_owned()), uri.host, "Unexpected host");
+		assert_eq!(Some(631), uri.port, "Unexpected port");
+		assert_eq!("/printers/office", uri.path, "Unexpected path");
+		assert_eq!(
+			Some("waitjob=false".to_owned()),
+			uri.query,
+			"Unexpected query"
+		);
+	}
+
+	#[test]
+	fn if_ipv6_uri_then_host_has_no_brackets() {
+		// A URI with an IPv6 host:
+		let uri = Uri::parse("socket://[fe80::1]:9100").expect("URI should be parsed");
+
+		// The host should be the bare address:
+		assert_eq!(Some("fe80::1".to_owned()), uri.host, "Unexpected host");
+		assert_eq!(Some(9100), uri.port, "Unexpected port");
+		assert_eq!("", uri.path, "Expected an empty path");
+	}
+
+	#[test]
+	fn if_uri_without_authority_then_only_path() {
+		// A URI without host:
+		let uri = Uri::parse("file:/dev/null").expect("URI should be parsed");
+
+		// Only the scheme and path should be parsed:
+		assert_eq!("file", uri.scheme, "Unexpected scheme");
+		assert_eq!(None, uri.host, "Expected no host");
+		assert_eq!("/dev/null", uri.path, "Unexpected path");
+	}
+
+	#[test]
+	fn if_printer_type_present_then_flags_are_decoded() {
+		// Color, duplex and staple:
+		let printer = printer_with_options(&[("printer-type", "56")]);
+
+		// The flags should be decoded:
+		assert_eq!(
+			Some(PrinterType::COLOR | PrinterType::DUPLEX | PrinterType::STAPLE),
+			printer.get_printer_type(),
+			"Expected color, duplex and staple flags"
+		);
+	}
+
+	fn printer_with_options(options: &[(&str, &str)]) -> Printer {
+		Printer {
+			identifier: "printer".to_owned(),
+			name: "printer".to_owned(),
+			instance: None,
+			is_default: false,
+			options: options
+				.iter()
+				.map(|(k, v)| (k.to_string(), v.to_string()))
+				.collect::<HashMap<_, _>>(),
+		}
+	}
+}
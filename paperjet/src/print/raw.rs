@@ -0,0 +1,214 @@
+//! Builders for documents in the command languages of label and receipt printers.
+//!
+//! The built documents are meant to be printed with [`crate::print_raw()`], which passes them to
+//! the printer unchanged.
+
+// MARK: - ESC/POS
+
+/// The ESC (escape) control character.
+const ESC: u8 = 0x1B;
+/// The GS (group separator) control character.
+const GS: u8 = 0x1D;
+/// The LF (line feed) control character.
+const LF: u8 = 0x0A;
+
+/// A builder of ESC/POS commands, as understood by most thermal receipt printers.
+///
+/// The printer is initialized at the start of the document.
+#[derive(Clone, Debug)]
+pub struct EscPos {
+	bytes: Vec<u8>,
+}
+impl EscPos {
+	/// Creates a document that initializes the printer.
+	pub fn new() -> Self {
+		Self {
+			bytes: vec![ESC, b'@'],
+		}
+	}
+	/// Appends text. The text is sent as UTF-8; use [`Self::raw()`] for text in the code page
+	/// selected on the printer.
+	pub fn text(mut self, text: &str) -> Self {
+		self.bytes.extend_from_slice(text.as_bytes());
+		self
+	}
+	/// Appends text, followed by a line feed.
+	pub fn line(self, text: &str) -> Self {
+		self.text(text).raw(&[LF])
+	}
+	/// Feeds the paper by the specified amount of lines.
+	pub fn feed(self, lines: u8) -> Self {
+		self.raw(&[ESC, b'd', lines])
+	}
+	/// Turns emphasized (bold) text on or off.
+	pub fn bold(self, enabled: bool) -> Self {
+		self.raw(&[ESC, b'E', u8::from(enabled)])
+	}
+	/// Sets the alignment of the following lines.
+	pub fn align(self, alignment: EscPosAlignment) -> Self {
+		self.raw(&[ESC, b'a', alignment as u8])
+	}
+	/// Appends a barcode of the specified kind, encoding `data`.
+	/// Data longer than 255 bytes is truncated.
+	pub fn barcode(self, kind: EscPosBarcode, data: &[u8]) -> Self {
+		let data = &data[..data.len().min(u8::MAX as usize)];
+		self.raw(&[GS, b'k', kind as u8, data.len() as u8])
+			.raw(data)
+	}
+	/// Cuts the paper.
+	pub fn cut(self, cut: EscPosCut) -> Self {
+		self.raw(&[GS, b'V', cut as u8])
+	}
+	/// Appends the specified bytes unchanged.
+	pub fn raw(mut self, bytes: &[u8]) -> Self {
+		self.bytes.extend_from_slice(bytes);
+		self
+	}
+	/// Returns the bytes of the document.
+	pub fn build(self) -> Vec<u8> {
+		self.bytes
+	}
+}
+impl Default for EscPos {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// The alignment of lines in an ESC/POS document.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EscPosAlignment {
+	Left = 0,
+	Center = 1,
+	Right = 2,
+}
+
+/// A kind of barcode in an ESC/POS document.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EscPosBarcode {
+	UpcA = 65,
+	UpcE = 66,
+	Ean13 = 67,
+	Ean8 = 68,
+	Code39 = 69,
+	Itf = 70,
+	Codabar = 71,
+	Code93 = 72,
+	/// Code 128. The data has to start with a code set selection, such as `{B`.
+	Code128 = 73,
+}
+
+/// The way the paper is cut in an ESC/POS document.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EscPosCut {
+	/// Cuts the paper completely.
+	Full = 0,
+	/// Leaves the paper connected at one point.
+	Partial = 1,
+}
+
+// MARK: - ZPL
+
+/// A builder of a ZPL label, as understood by Zebra and compatible label printers.
+///
+/// Positions and heights are specified in dots.
+#[derive(Clone, Debug)]
+pub struct Zpl {
+	commands: String,
+}
+impl Zpl {
+	/// Creates an empty label.
+	pub fn new() -> Self {
+		Self {
+			commands: String::from("^XA"),
+		}
+	}
+	/// Appends text at the specified position, using the printer's scalable font.
+	pub fn text(mut self, x: u32, y: u32, height: u32, text: &str) -> Self {
+		self.commands.push_str(&format!(
+			"^FO{x},{y}^A0N,{height},{height}^FH^FD{}^FS",
+			escape_field_data(text)
+		));
+		self
+	}
+	/// Appends a Code 128 barcode at the specified position, with the data printed below it.
+	pub fn barcode_128(mut self, x: u32, y: u32, height: u32, data: &str) -> Self {
+		self.commands.push_str(&format!(
+			"^FO{x},{y}^BCN,{height},Y,N,N^FH^FD{}^FS",
+			escape_field_data(data)
+		));
+		self
+	}
+	/// Cuts the media after the label is printed (requires a cutter).
+	pub fn cut(mut self) -> Self {
+		self.commands.push_str("^MMC");
+		self
+	}
+	/// Appends the specified commands unchanged.
+	pub fn raw(mut self, commands: &str) -> Self {
+		self.commands.push_str(commands);
+		self
+	}
+	/// Returns the bytes of the label.
+	pub fn build(mut self) -> Vec<u8> {
+		self.commands.push_str("^XZ");
+		self.commands.into_bytes()
+	}
+}
+impl Default for Zpl {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Escapes the characters of field data that ZPL would interpret as commands, using hexadecimal
+/// escapes (enabled with `^FH`).
+fn escape_field_data(data: &str) -> String {
+	data.replace('_', "_5F")
+		.replace('^', "_5E")
+		.replace('~', "_7E")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn if_esc_pos_commands_are_added_then_document_contains_them_in_order() {
+		// Centered text, a barcode, and a partial cut:
+		let bytes = EscPos::new()
+			.align(EscPosAlignment::Center)
+			.line("Hi")
+			.barcode(EscPosBarcode::Ean8, b"1234567")
+			.cut(EscPosCut::Partial)
+			.build();
+
+		// The document should start with initialization, followed by the commands in order:
+		let expected = [
+			&[ESC, b'@'][..],
+			&[ESC, b'a', 1],
+			b"Hi\n",
+			&[GS, b'k', 68, 7],
+			b"1234567",
+			&[GS, b'V', 1],
+		]
+		.concat();
+		assert_eq!(
+			bytes, expected,
+			"Document should contain the commands in order, was: {bytes:?}"
+		);
+	}
+
+	#[test]
+	fn if_zpl_text_has_control_characters_then_field_data_is_escaped() {
+		// Text containing the ZPL control characters `^`, `~` and the escape character `_`:
+		let bytes = Zpl::new().text(10, 20, 30, "a^b~c_d").build();
+
+		// The characters should be hex-escaped within the field data:
+		let label = String::from_utf8(bytes).expect("Label should be valid UTF-8");
+		assert_eq!(
+			label, "^XA^FO10,20^A0N,30,30^FH^FDa_5Eb_7Ec_5Fd^FS^XZ",
+			"Control characters should be escaped, label was: {label}"
+		);
+	}
+}
@@ -0,0 +1,132 @@
+use std::ffi::{CStr, CString};
+use std::ptr;
+
+use crate::error::PrintError;
+use crate::options::PrintOptions;
+use crate::print::unix::cups;
+use crate::print::unix::cups::consts::opts;
+use crate::print::unix::dest::{CupsDestination, CupsDestinationInfo};
+use crate::print::unix::job;
+use crate::print::unix::options::{CupsOptions, replace_cups_option, visit_options};
+use crate::print::util::FatPointerMut;
+use crate::validation::{ConflictResolution, OptionConflict};
+
+/// Checks each of the `options` for conflicts with the remaining options.
+///
+/// If CUPS suggests a resolution for a conflict, it is applied to the options before the next
+/// option is checked. If all conflicts could be resolved this way, the adjusted options are
+/// returned; otherwise, all conflicts that were encountered are returned.
+pub fn resolve_conflicts(
+	destination: &mut CupsDestination,
+	info: &mut CupsDestinationInfo,
+	options: &PrintOptions,
+) -> Result<ConflictResolution, PrintError> {
+	let mut cups_options = CupsOptions::new();
+	visit_options(options, &mut cups_options)?;
+	for (name, value) in &options.extra {
+		cups_options.add_raw(
+			&CString::new(name.as_str())?,
+			&CString::new(value.as_str())?,
+		);
+	}
+	// The job password does not conflict with other options, and must not be exposed in the
+	// reported conflicts (it is kept in the resolved options, which are cloned from `options`):
+	cups_options.remove(opts::CUPS_JOB_PASSWORD);
+
+	let mut conflicts = Vec::new();
+	let names = cups_options
+		.to_pairs()
+		.into_iter()
+		.map(|(name, _)| name)
+		.collect::<Vec<_>>();
+	for name in names {
+		// The value may have been changed by the resolution of a previous conflict:
+		let Some(value) = cups_options.get(&name) else {
+			continue;
+		};
+		let (c_name, c_value) = (CString::new(name.as_str())?, CString::new(value.as_str())?);
+		let Some((conflicting, resolved)) =
+			copy_conflicts(destination, info, &mut cups_options, &c_name, &c_value)?
+		else {
+			continue;
+		};
+
+		let resolution = resolved.to_pairs();
+		for (res_name, res_value) in &resolution {
+			cups_options.add_raw(
+				&CString::new(res_name.as_str())?,
+				&CString::new(res_value.as_str())?,
+			);
+		}
+		conflicts.push(OptionConflict {
+			option: (name, value),
+			conflicting: conflicting.to_pairs(),
+			resolution,
+		});
+	}
+
+	if conflicts
+		.iter()
+		.any(|conflict| conflict.resolution.is_empty())
+	{
+		return Ok(ConflictResolution::Unresolved(conflicts));
+	}
+	// Map the adjusted values back to the options, keeping settings that are not CUPS options:
+	let mut resolved = options.clone();
+	for (name, value) in cups_options.to_pairs() {
+		replace_cups_option(&mut resolved, &name, &value);
+	}
+	Ok(ConflictResolution::Resolved(Box::new(resolved)))
+}
+
+/// Retrieves the options that conflict with the option `name` = `value`, as well as the options
+/// that resolve the conflicts (empty if CUPS could not find a resolution).
+/// Returns `None` if there are no conflicts.
+fn copy_conflicts(
+	destination: &mut CupsDestination,
+	info: &mut CupsDestinationInfo,
+	options: &mut CupsOptions,
+	name: &CStr,
+	value: &CStr,
+) -> Result<Option<(CupsOptions, CupsOptions)>, PrintError> {
+	let mut conflicts = FatPointerMut {
+		size: 0,
+		ptr: ptr::null_mut(),
+	};
+	let mut resolved = FatPointerMut {
+		size: 0,
+		ptr: ptr::null_mut(),
+	};
+	// SAFETY: `destination` and `info` are safe wrappers containing valid pointers, and `options`
+	// contains a valid fat pointer (or a null pointer, which CUPS accepts for an empty list).
+	// `cupsCopyDestConflicts` writes valid pointers to arrays it allocated (or null pointers) into
+	// `conflicts` and `resolved`, along with their sizes.
+	let result = unsafe {
+		cups::cupsCopyDestConflicts(
+			cups::consts::http::CUPS_HTTP_DEFAULT,
+			destination.as_mut_ptr(),
+			info.as_mut_ptr(),
+			options.as_fat_ptr_mut().size,
+			options.as_fat_ptr_mut().ptr,
+			name.as_ptr(),
+			value.as_ptr(),
+			&mut conflicts.size,
+			&mut conflicts.ptr,
+			&mut resolved.size,
+			&mut resolved.ptr,
+		)
+	};
+	// SAFETY: the arrays were allocated by CUPS in the call above, and are not freed anywhere else.
+	let (conflicts, resolved) = unsafe {
+		(
+			CupsOptions::from_fat_ptr(conflicts),
+			CupsOptions::from_fat_ptr(resolved),
+		)
+	};
+
+	match result {
+		0 => Ok(None),
+		1 => Ok(Some((conflicts, resolved))),
+		_ => Err(job::get_last_error()),
+	}
+}
@@ -25,31 +25,136 @@ pub mod consts {
 	pub mod http {
 		use super::*;
 		pub const CUPS_HTTP_DEFAULT: *mut http_t = null_mut();
+		pub const CUPS_LENGTH_VARIABLE: usize = 0;
+	}
+
+	pub mod media {
+		use std::ffi::c_uint;
+		pub const CUPS_MEDIA_FLAGS_BORDERLESS: c_uint = 0x01;
 	}
 
 	pub mod opts {
 		use super::*;
+		const_cstr!(CUPS_COLLATE = c"collate");
+		const_cstr!(CUPS_COMPRESSION = c"compression");
 		const_cstr!(CUPS_COPIES = c"copies");
 		const_cstr!(CUPS_FINISHINGS = c"finishings");
+		const_cstr!(CUPS_FINISHINGS_COL = c"finishings-col");
+		const_cstr!(CUPS_FINISHING_TEMPLATE = c"finishing-template");
+		const_cstr!(CUPS_FIT_TO_PAGE = c"fit-to-page");
+		const_cstr!(CUPS_JOB_ACCOUNT_ID = c"job-account-id");
+		const_cstr!(CUPS_JOB_ACCOUNTING_USER_ID = c"job-accounting-user-id");
+		const_cstr!(CUPS_JOB_PASSWORD = c"job-password");
+		const_cstr!(CUPS_JOB_PASSWORD_ENCRYPTION = c"job-password-encryption");
+		const_cstr!(CUPS_JOB_PRIORITY = c"job-priority");
+		const_cstr!(CUPS_JOB_RETAIN_UNTIL = c"job-retain-until");
+		const_cstr!(CUPS_JOB_SHEETS = c"job-sheets");
 		const_cstr!(CUPS_MEDIA = c"media");
+		const_cstr!(CUPS_MEDIA_COL = c"media-col");
 		const_cstr!(CUPS_MEDIA_SOURCE = c"media-source");
 		const_cstr!(CUPS_MEDIA_TYPE = c"media-type");
+		const_cstr!(CUPS_MIRROR = c"mirror");
+		const_cstr!(CUPS_MULTIPLE_DOCUMENT_HANDLING = c"multiple-document-handling");
 		const_cstr!(CUPS_NUMBER_UP = c"number-up");
+		const_cstr!(CUPS_NUMBER_UP_LAYOUT = c"number-up-layout");
 		const_cstr!(CUPS_ORIENTATION = c"orientation-requested");
+		const_cstr!(CUPS_OUTPUT_BIN = c"output-bin");
+		const_cstr!(CUPS_OUTPUT_ORDER = c"outputorder");
+		const_cstr!(CUPS_PAGE_BORDER = c"page-border");
+		const_cstr!(CUPS_PAGE_RANGES = c"page-ranges");
 		const_cstr!(CUPS_PRINT_COLOR_MODE = c"print-color-mode");
 		const_cstr!(CUPS_PRINT_QUALITY = c"print-quality");
+		const_cstr!(CUPS_PRINT_RENDERING_INTENT = c"print-rendering-intent");
+		const_cstr!(CUPS_PRINT_SCALING = c"print-scaling");
+		const_cstr!(CUPS_PRINTER_RESOLUTION = c"printer-resolution");
 		const_cstr!(CUPS_SIDES = c"sides");
 
 		pub mod values {
 			use super::*;
+			// Compression
+			const_cstr!(CUPS_COMPRESSION_NONE = c"none");
+			const_cstr!(CUPS_COMPRESSION_GZIP = c"gzip");
 			// Finishings
-			const_cstr!(CUPS_FINISHINGS_BIND = c"7");
-			const_cstr!(CUPS_FINISHINGS_COVER = c"6");
-			const_cstr!(CUPS_FINISHINGS_FOLD = c"10");
 			const_cstr!(CUPS_FINISHINGS_NONE = c"3");
-			const_cstr!(CUPS_FINISHINGS_PUNCH = c"5");
 			const_cstr!(CUPS_FINISHINGS_STAPLE = c"4");
+			const_cstr!(CUPS_FINISHINGS_PUNCH = c"5");
+			const_cstr!(CUPS_FINISHINGS_COVER = c"6");
+			const_cstr!(CUPS_FINISHINGS_BIND = c"7");
+			const_cstr!(CUPS_FINISHINGS_SADDLE_STITCH = c"8");
+			const_cstr!(CUPS_FINISHINGS_EDGE_STITCH = c"9");
+			const_cstr!(CUPS_FINISHINGS_FOLD = c"10");
 			const_cstr!(CUPS_FINISHINGS_TRIM = c"11");
+			const_cstr!(CUPS_FINISHINGS_BALE = c"12");
+			const_cstr!(CUPS_FINISHINGS_BOOKLET_MAKER = c"13");
+			const_cstr!(CUPS_FINISHINGS_JOG_OFFSET = c"14");
+			const_cstr!(CUPS_FINISHINGS_COAT = c"15");
+			const_cstr!(CUPS_FINISHINGS_LAMINATE = c"16");
+			const_cstr!(CUPS_FINISHINGS_STAPLE_TOP_LEFT = c"20");
+			const_cstr!(CUPS_FINISHINGS_STAPLE_BOTTOM_LEFT = c"21");
+			const_cstr!(CUPS_FINISHINGS_STAPLE_TOP_RIGHT = c"22");
+			const_cstr!(CUPS_FINISHINGS_STAPLE_BOTTOM_RIGHT = c"23");
+			const_cstr!(CUPS_FINISHINGS_EDGE_STITCH_LEFT = c"24");
+			const_cstr!(CUPS_FINISHINGS_EDGE_STITCH_TOP = c"25");
+			const_cstr!(CUPS_FINISHINGS_EDGE_STITCH_RIGHT = c"26");
+			const_cstr!(CUPS_FINISHINGS_EDGE_STITCH_BOTTOM = c"27");
+			const_cstr!(CUPS_FINISHINGS_STAPLE_DUAL_LEFT = c"28");
+			const_cstr!(CUPS_FINISHINGS_STAPLE_DUAL_TOP = c"29");
+			const_cstr!(CUPS_FINISHINGS_STAPLE_DUAL_RIGHT = c"30");
+			const_cstr!(CUPS_FINISHINGS_STAPLE_DUAL_BOTTOM = c"31");
+			const_cstr!(CUPS_FINISHINGS_STAPLE_TRIPLE_LEFT = c"32");
+			const_cstr!(CUPS_FINISHINGS_STAPLE_TRIPLE_TOP = c"33");
+			const_cstr!(CUPS_FINISHINGS_STAPLE_TRIPLE_RIGHT = c"34");
+			const_cstr!(CUPS_FINISHINGS_STAPLE_TRIPLE_BOTTOM = c"35");
+			const_cstr!(CUPS_FINISHINGS_BIND_LEFT = c"50");
+			const_cstr!(CUPS_FINISHINGS_BIND_TOP = c"51");
+			const_cstr!(CUPS_FINISHINGS_BIND_RIGHT = c"52");
+			const_cstr!(CUPS_FINISHINGS_BIND_BOTTOM = c"53");
+			const_cstr!(CUPS_FINISHINGS_TRIM_AFTER_PAGES = c"60");
+			const_cstr!(CUPS_FINISHINGS_TRIM_AFTER_DOCUMENTS = c"61");
+			const_cstr!(CUPS_FINISHINGS_TRIM_AFTER_COPIES = c"62");
+			const_cstr!(CUPS_FINISHINGS_TRIM_AFTER_JOB = c"63");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_TOP_LEFT = c"70");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_BOTTOM_LEFT = c"71");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_TOP_RIGHT = c"72");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_BOTTOM_RIGHT = c"73");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_DUAL_LEFT = c"74");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_DUAL_TOP = c"75");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_DUAL_RIGHT = c"76");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_DUAL_BOTTOM = c"77");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_TRIPLE_LEFT = c"78");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_TRIPLE_TOP = c"79");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_TRIPLE_RIGHT = c"80");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_TRIPLE_BOTTOM = c"81");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_QUAD_LEFT = c"82");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_QUAD_TOP = c"83");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_QUAD_RIGHT = c"84");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_QUAD_BOTTOM = c"85");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_MULTIPLE_LEFT = c"86");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_MULTIPLE_TOP = c"87");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_MULTIPLE_RIGHT = c"88");
+			const_cstr!(CUPS_FINISHINGS_PUNCH_MULTIPLE_BOTTOM = c"89");
+			const_cstr!(CUPS_FINISHINGS_FOLD_ACCORDION = c"90");
+			const_cstr!(CUPS_FINISHINGS_FOLD_DOUBLE_GATE = c"91");
+			const_cstr!(CUPS_FINISHINGS_FOLD_GATE = c"92");
+			const_cstr!(CUPS_FINISHINGS_FOLD_HALF = c"93");
+			const_cstr!(CUPS_FINISHINGS_FOLD_HALF_Z = c"94");
+			const_cstr!(CUPS_FINISHINGS_FOLD_LEFT_GATE = c"95");
+			const_cstr!(CUPS_FINISHINGS_FOLD_LETTER = c"96");
+			const_cstr!(CUPS_FINISHINGS_FOLD_PARALLEL = c"97");
+			const_cstr!(CUPS_FINISHINGS_FOLD_POSTER = c"98");
+			const_cstr!(CUPS_FINISHINGS_FOLD_RIGHT_GATE = c"99");
+			const_cstr!(CUPS_FINISHINGS_FOLD_Z = c"100");
+			const_cstr!(CUPS_FINISHINGS_FOLD_ENGINEERING_Z = c"101");
+			// Job password encryption
+			const_cstr!(CUPS_JOB_PASSWORD_ENCRYPTION_NONE = c"none");
+			// Job retention
+			const_cstr!(CUPS_JOB_RETAIN_UNTIL_INDEFINITE = c"indefinite");
+			const_cstr!(CUPS_JOB_RETAIN_UNTIL_DAY_TIME = c"day-time");
+			const_cstr!(CUPS_JOB_RETAIN_UNTIL_EVENING = c"evening");
+			const_cstr!(CUPS_JOB_RETAIN_UNTIL_NIGHT = c"night");
+			const_cstr!(CUPS_JOB_RETAIN_UNTIL_WEEKEND = c"weekend");
+			const_cstr!(CUPS_JOB_RETAIN_UNTIL_SECOND_SHIFT = c"second-shift");
+			const_cstr!(CUPS_JOB_RETAIN_UNTIL_THIRD_SHIFT = c"third-shift");
 			// Media
 			const_cstr!(CUPS_MEDIA_3X5 = c"na_index-3x5_3x5in");
 			const_cstr!(CUPS_MEDIA_4X6 = c"na_index-4x6_4x6in");
@@ -66,9 +171,6 @@ pub mod consts {
 			const_cstr!(CUPS_MEDIA_PHOTO_L = c"oe_photo-l_3.5x5in");
 			const_cstr!(CUPS_MEDIA_SUPERBA3 = c"na_super-b_13x19in");
 			const_cstr!(CUPS_MEDIA_TABLOID = c"na_ledger_11x17in");
-			// Media source
-			const_cstr!(CUPS_MEDIA_SOURCE_AUTO = c"auto");
-			const_cstr!(CUPS_MEDIA_SOURCE_MANUAL = c"manual");
 			// Media type
 			const_cstr!(CUPS_MEDIA_TYPE_AUTO = c"auto");
 			const_cstr!(CUPS_MEDIA_TYPE_ENVELOPE = c"envelope");
@@ -79,17 +181,66 @@ pub mod consts {
 			const_cstr!(CUPS_MEDIA_TYPE_PHOTO_MATTE = c"photographic-matte");
 			const_cstr!(CUPS_MEDIA_TYPE_PLAIN = c"stationery");
 			const_cstr!(CUPS_MEDIA_TYPE_TRANSPARENCY = c"transparency");
+			// Number up layout
+			const_cstr!(CUPS_NUMBER_UP_LAYOUT_LRTB = c"lrtb");
+			const_cstr!(CUPS_NUMBER_UP_LAYOUT_LRBT = c"lrbt");
+			const_cstr!(CUPS_NUMBER_UP_LAYOUT_RLTB = c"rltb");
+			const_cstr!(CUPS_NUMBER_UP_LAYOUT_RLBT = c"rlbt");
+			const_cstr!(CUPS_NUMBER_UP_LAYOUT_TBLR = c"tblr");
+			const_cstr!(CUPS_NUMBER_UP_LAYOUT_TBRL = c"tbrl");
+			const_cstr!(CUPS_NUMBER_UP_LAYOUT_BTLR = c"btlr");
+			const_cstr!(CUPS_NUMBER_UP_LAYOUT_BTRL = c"btrl");
+			// Multiple document handling
+			const_cstr!(
+				CUPS_MULTIPLE_DOCUMENT_HANDLING_COLLATED = c"separate-documents-collated-copies"
+			);
+			const_cstr!(
+				CUPS_MULTIPLE_DOCUMENT_HANDLING_UNCOLLATED =
+					c"separate-documents-uncollated-copies"
+			);
 			// Orientation
 			const_cstr!(CUPS_ORIENTATION_PORTRAIT = c"3");
 			const_cstr!(CUPS_ORIENTATION_LANDSCAPE = c"4");
+			const_cstr!(CUPS_ORIENTATION_REVERSE_LANDSCAPE = c"5");
+			const_cstr!(CUPS_ORIENTATION_REVERSE_PORTRAIT = c"6");
+			const_cstr!(CUPS_ORIENTATION_NONE = c"7");
+			// Output order
+			const_cstr!(CUPS_OUTPUT_ORDER_NORMAL = c"normal");
+			const_cstr!(CUPS_OUTPUT_ORDER_REVERSE = c"reverse");
+			// Page border
+			const_cstr!(CUPS_PAGE_BORDER_NONE = c"none");
+			const_cstr!(CUPS_PAGE_BORDER_SINGLE = c"single");
+			const_cstr!(CUPS_PAGE_BORDER_SINGLE_THICK = c"single-thick");
+			const_cstr!(CUPS_PAGE_BORDER_DOUBLE = c"double");
+			const_cstr!(CUPS_PAGE_BORDER_DOUBLE_THICK = c"double-thick");
+			// Print scaling
+			const_cstr!(CUPS_PRINT_SCALING_AUTO = c"auto");
+			const_cstr!(CUPS_PRINT_SCALING_AUTO_FIT = c"auto-fit");
+			const_cstr!(CUPS_PRINT_SCALING_FILL = c"fill");
+			const_cstr!(CUPS_PRINT_SCALING_FIT = c"fit");
+			const_cstr!(CUPS_PRINT_SCALING_NONE = c"none");
+			// Booleans
+			const_cstr!(CUPS_TRUE = c"true");
+			const_cstr!(CUPS_FALSE = c"false");
 			// Color mode
 			const_cstr!(CUPS_PRINT_COLOR_MODE_AUTO = c"auto");
 			const_cstr!(CUPS_PRINT_COLOR_MODE_MONOCHROME = c"monochrome");
 			const_cstr!(CUPS_PRINT_COLOR_MODE_COLOR = c"color");
+			const_cstr!(CUPS_PRINT_COLOR_MODE_BI_LEVEL = c"bi-level");
+			const_cstr!(CUPS_PRINT_COLOR_MODE_PROCESS_MONOCHROME = c"process-monochrome");
+			const_cstr!(CUPS_PRINT_COLOR_MODE_HIGHLIGHT = c"highlight");
+			const_cstr!(CUPS_PRINT_COLOR_MODE_AUTO_MONOCHROME = c"auto-monochrome");
 			// Quality
 			const_cstr!(CUPS_PRINT_QUALITY_DRAFT = c"3");
 			const_cstr!(CUPS_PRINT_QUALITY_NORMAL = c"4");
 			const_cstr!(CUPS_PRINT_QUALITY_HIGH = c"5");
+			// Rendering intent
+			const_cstr!(CUPS_PRINT_RENDERING_INTENT_AUTO = c"auto");
+			const_cstr!(CUPS_PRINT_RENDERING_INTENT_PERCEPTUAL = c"perceptual");
+			const_cstr!(CUPS_PRINT_RENDERING_INTENT_RELATIVE = c"relative");
+			const_cstr!(CUPS_PRINT_RENDERING_INTENT_RELATIVE_BPC = c"relative-bpc");
+			const_cstr!(CUPS_PRINT_RENDERING_INTENT_SATURATION = c"saturation");
+			const_cstr!(CUPS_PRINT_RENDERING_INTENT_ABSOLUTE = c"absolute");
 			// Sides
 			const_cstr!(CUPS_SIDES_ONE_SIDED = c"one-sided");
 			const_cstr!(CUPS_SIDES_TWO_SIDED_PORTRAIT = c"two-sided-long-edge");
@@ -100,5 +251,6 @@ pub mod consts {
 	pub mod format {
 		use super::*;
 		const_cstr!(CUPS_FORMAT_AUTO = c"application/octet-stream");
+		const_cstr!(CUPS_FORMAT_RAW = c"application/vnd.cups-raw");
 	}
 }
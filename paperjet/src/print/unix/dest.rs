@@ -1,10 +1,12 @@
-use std::ffi::CStr;
+use std::ffi::{CStr, c_uint};
 use std::marker::PhantomData;
 use std::ops::Deref;
 use std::ops::DerefMut;
-use std::ptr;
+use std::{ptr, slice};
 
 use crate::print::unix::cups;
+use crate::print::unix::ipp::IppAttribute;
+use crate::print::util;
 use crate::print::util::FatPointerMut;
 
 // NOTE: this file contains safe wrappers for unsafe CUPS bindings.
@@ -114,6 +116,21 @@ impl<'a> CupsDestination<'a> {
 			})
 		}
 	}
+	/// Returns the value of the option with the specified name (such as `printer-uri-supported`),
+	/// or `None` if the destination has no such option.
+	pub fn get_option(&self, name: &str) -> Option<String> {
+		if self.options.is_null() {
+			return None;
+		}
+		// SAFETY: `self.options` is a valid, non-null pointer to `self.num_options` options, and
+		// each option consists of valid C strings, all owned by the destination.
+		unsafe {
+			slice::from_raw_parts(self.options, self.num_options as usize)
+				.iter()
+				.find(|option| util::cstr_to_str(option.name) == name)
+				.map(|option| util::cstr_to_string(option.value))
+		}
+	}
 	// Returns the raw mutable pointer to this destination.
 	pub fn as_mut_ptr(&mut self) -> *mut cups::cups_dest_t {
 		self.ptr
@@ -157,6 +174,65 @@ impl CupsDestinationInfo {
 		}
 		Some(CupsDestinationInfo(ptr))
 	}
+	/// Returns the values of `option` that are supported by `destination`, converted to strings.
+	/// Returns an empty vector if the destination does not report any supported values.
+	pub fn get_supported_values(
+		&mut self,
+		destination: &mut CupsDestination,
+		option: &CStr,
+	) -> Vec<String> {
+		// SAFETY: `destination` is wrapped in CupsDestination, and `self.0` is a valid pointer
+		// obtained in `Self::new`. `cupsFindDestSupported` returns a pointer to an attribute owned
+		// by the destination info, or a null pointer.
+		let ptr = unsafe {
+			cups::cupsFindDestSupported(
+				cups::consts::http::CUPS_HTTP_DEFAULT,
+				destination.as_mut_ptr(),
+				self.0,
+				option.as_ptr(),
+			)
+		};
+		// SAFETY: the attribute remains valid while `self` is borrowed, and its values are copied
+		// before returning.
+		unsafe { IppAttribute::from_ptr(ptr) }
+			.map(|attribute| attribute.values())
+			.unwrap_or_default()
+	}
+	/// Looks up the size of the media with the specified name (or the default media, if `None`),
+	/// matching `flags` (for example, [`cups::consts::media::CUPS_MEDIA_FLAGS_BORDERLESS`]).
+	/// Returns `None` if the destination does not support such media.
+	pub fn get_media_size(
+		&mut self,
+		destination: &mut CupsDestination,
+		media: Option<&CStr>,
+		flags: c_uint,
+	) -> Option<cups::cups_size_t> {
+		// SAFETY: `cups_size_t` is a plain C struct, for which all zero bytes are a valid value.
+		let mut size = unsafe { std::mem::zeroed::<cups::cups_size_t>() };
+		// SAFETY: `destination` is wrapped in CupsDestination, `self.0` is a valid pointer obtained
+		// in `Self::new`, `media` is a valid C string, and `size` is a valid struct that CUPS writes
+		// into.
+		let found = unsafe {
+			match media {
+				Some(media) => cups::cupsGetDestMediaByName(
+					cups::consts::http::CUPS_HTTP_DEFAULT,
+					destination.as_mut_ptr(),
+					self.0,
+					media.as_ptr(),
+					flags,
+					&mut size,
+				),
+				None => cups::cupsGetDestMediaDefault(
+					cups::consts::http::CUPS_HTTP_DEFAULT,
+					destination.as_mut_ptr(),
+					self.0,
+					flags,
+					&mut size,
+				),
+			}
+		};
+		(found != 0).then_some(size)
+	}
 	/// Returns the raw mutable pointer to the destination info instance.
 	pub fn as_mut_ptr(&mut self) -> *mut cups::cups_dinfo_t {
 		self.0
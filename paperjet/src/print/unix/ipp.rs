@@ -0,0 +1,486 @@
+use std::ffi::{CStr, CString, c_char, c_int};
+use std::marker::PhantomData;
+use std::ptr;
+
+use crate::error::PrintError;
+use crate::print::Printer;
+use crate::print::printer::PrinterType;
+use crate::print::unix::options::CupsOptions;
+use crate::print::unix::{cups, job};
+use crate::print::util;
+
+// NOTE: this file contains safe wrappers for building IPP requests, sending them to the CUPS
+// server, and reading the responses. These are used for operations that CUPS does not offer
+// a dedicated function for (such as subscriptions).
+
+/// Returns the URI of the CUPS server that requests are sent to, such as `ipp://localhost:631/`.
+/// This is used for operations that are not specific to a printer.
+pub fn get_server_uri() -> Result<CString, PrintError> {
+	Ok(CString::new(format!("ipp://{}/", get_server_authority()))?)
+}
+
+/// Returns the URI of the specified printer on the CUPS server.
+///
+/// This is the first of the URIs the printer reports in `printer-uri-supported`. If these are not
+/// known, the URI is built from the printer's name, using the resource of classes or printers
+/// depending on the printer type.
+pub fn get_printer_uri(printer: &Printer) -> Result<CString, PrintError> {
+	if let Some(uri) = printer
+		.get_list_option("printer-uri-supported")
+		.into_iter()
+		.find(|uri| !uri.is_empty())
+	{
+		return Ok(CString::new(uri)?);
+	}
+	let is_class = printer
+		.get_printer_type()
+		.is_some_and(|printer_type| printer_type.contains(PrinterType::CLASS));
+	let resource = if is_class { "classes" } else { "printers" };
+	let uri = format!(
+		"ipp://{}/{resource}/{}",
+		get_server_authority(),
+		printer.identifier
+	);
+	Ok(CString::new(uri)?)
+}
+
+/// Returns the URI of the job with the specified ID on the CUPS server.
+pub fn get_job_uri(job_id: i32) -> Result<CString, PrintError> {
+	Ok(CString::new(format!(
+		"ipp://{}/jobs/{job_id}",
+		get_server_authority()
+	))?)
+}
+
+/// Returns the host and port of the CUPS server that requests are sent to (as configured with
+/// `CUPS_SERVER` or `client.conf`). Servers listening on a domain socket are addressed as
+/// `localhost`, as CUPS does.
+fn get_server_authority() -> String {
+	// SAFETY: `cupsServer` and `ippPort` accept no arguments. `cupsServer` returns a valid C string.
+	let (server, port) = unsafe { (util::cstr_to_string(cups::cupsServer()), cups::ippPort()) };
+	if server.starts_with('/') {
+		return String::from("localhost");
+	}
+	// IPv6 addresses are enclosed in brackets:
+	if server.contains(':') && !server.starts_with('[') {
+		return format!("[{server}]:{port}");
+	}
+	format!("{server}:{port}")
+}
+
+// MARK: - Request
+
+/// A struct representing an IPP request that has not been sent yet.
+pub struct IppRequest(*mut cups::ipp_t);
+impl IppRequest {
+	/// Creates a new request for the specified operation.
+	/// The request contains the `attributes-charset`, `attributes-natural-language` and
+	/// `requesting-user-name` operation attributes.
+	pub fn new(operation: cups::ipp_op_e) -> Self {
+		// SAFETY: `cupsUser` accepts no arguments, and returns a valid C string.
+		let user = unsafe { CStr::from_ptr(cups::cupsUser()) }.to_owned();
+		Self::new_as_user(operation, &user)
+	}
+	/// Creates a new request for the specified operation, made as the user `user` instead of the
+	/// current CUPS user. The user is sent in the `requesting-user-name` operation attribute.
+	pub fn new_as_user(operation: cups::ipp_op_e, user: &CStr) -> Self {
+		// SAFETY: `ippNewRequest` accepts any operation code, and returns a valid pointer to a new
+		// request, which is freed on drop (or by `cupsDoRequest`).
+		let mut request = Self(unsafe { cups::ippNewRequest(operation) });
+		request.add_string(
+			cups::ipp_tag_e::IPP_TAG_OPERATION,
+			cups::ipp_tag_e::IPP_TAG_NAME,
+			c"requesting-user-name",
+			user,
+		);
+		request
+	}
+	/// Adds a string-based attribute (such as a keyword, URI, or name) to this request.
+	pub fn add_string(
+		&mut self,
+		group: cups::ipp_tag_e,
+		value_tag: cups::ipp_tag_e,
+		name: &CStr,
+		value: &CStr,
+	) {
+		// SAFETY: `self.0` is a valid request, and `name` and `value` are valid C strings, which
+		// are copied by CUPS. A null pointer for the language is accepted.
+		unsafe {
+			cups::ippAddString(
+				self.0,
+				group,
+				value_tag,
+				name.as_ptr(),
+				ptr::null(),
+				value.as_ptr(),
+			)
+		};
+	}
+	/// Adds a string-based attribute with several values to this request.
+	/// If `values` is empty, nothing is added.
+	pub fn add_strings(
+		&mut self,
+		group: cups::ipp_tag_e,
+		value_tag: cups::ipp_tag_e,
+		name: &CStr,
+		values: &[CString],
+	) {
+		if values.is_empty() {
+			return;
+		}
+		let pointers = values
+			.iter()
+			.map(|value| value.as_ptr())
+			.collect::<Vec<*const c_char>>();
+		// SAFETY: `self.0` is a valid request, and `pointers` contains `pointers.len()` valid C
+		// strings, which are copied by CUPS. A null pointer for the language is accepted.
+		unsafe {
+			cups::ippAddStrings(
+				self.0,
+				group,
+				value_tag,
+				name.as_ptr(),
+				pointers.len() as c_int,
+				ptr::null(),
+				pointers.as_ptr(),
+			)
+		};
+	}
+	/// Adds an integer or enum attribute to this request.
+	pub fn add_integer(
+		&mut self,
+		group: cups::ipp_tag_e,
+		value_tag: cups::ipp_tag_e,
+		name: &CStr,
+		value: c_int,
+	) {
+		// SAFETY: `self.0` is a valid request, and `name` is a valid C string, copied by CUPS.
+		unsafe { cups::ippAddInteger(self.0, group, value_tag, name.as_ptr(), value) };
+	}
+	/// Adds a boolean attribute to this request.
+	pub fn add_boolean(&mut self, group: cups::ipp_tag_e, name: &CStr, value: bool) {
+		// SAFETY: `self.0` is a valid request, and `name` is a valid C string, copied by CUPS.
+		unsafe { cups::ippAddBoolean(self.0, group, name.as_ptr(), value as c_char) };
+	}
+	/// Adds the options in `options` that belong to the attribute group `group` (such as the job
+	/// template attributes, for [`cups::ipp_tag_e::IPP_TAG_JOB`]) to this request, as CUPS does.
+	/// Options of other groups are skipped.
+	pub fn add_options(&mut self, options: &mut CupsOptions, group: cups::ipp_tag_e) {
+		let options = options.as_fat_ptr_mut();
+		// SAFETY: `self.0` is a valid request, and `options` points to `options.size` valid options
+		// (or is null if there are none), which are copied by CUPS.
+		unsafe { cups::cupsEncodeOptions2(self.0, options.size, options.ptr, group) };
+	}
+	/// Sends this request to the CUPS server and returns the response.
+	/// Returns `Err` if the request could not be sent, or if the server responded with an error.
+	pub fn send(self, resource: &CStr) -> Result<IppResponse, PrintError> {
+		// `cupsDoRequest` frees the request, so it must not be freed on drop:
+		let request = self.0;
+		std::mem::forget(self);
+
+		// SAFETY: `request` is a valid request, which is freed by `cupsDoRequest`. The returned
+		// response is either null, or a valid pointer that is freed when `IppResponse` is dropped.
+		let response = unsafe {
+			cups::cupsDoRequest(
+				cups::consts::http::CUPS_HTTP_DEFAULT,
+				request,
+				resource.as_ptr(),
+			)
+		};
+		IppResponse::try_from_ptr(response)
+	}
+	/// Sends this request to the CUPS server, to be followed by data of variable length (such as a
+	/// document), which is written with `cupsWriteRequestData`. Once all data has been written,
+	/// the response must be read with [`receive_response()`].
+	/// Returns `Err` if the request could not be sent.
+	pub fn send_with_data(self, resource: &CStr) -> Result<(), PrintError> {
+		// SAFETY: `self.0` is a valid request, which is not freed by `cupsSendRequest`, and
+		// `resource` is a valid C string.
+		let status = unsafe {
+			cups::cupsSendRequest(
+				cups::consts::http::CUPS_HTTP_DEFAULT,
+				self.0,
+				resource.as_ptr(),
+				cups::consts::http::CUPS_LENGTH_VARIABLE,
+			)
+		};
+		if status != cups::http_status_e::HTTP_STATUS_CONTINUE {
+			return Err(job::get_last_error());
+		}
+		Ok(())
+	}
+}
+impl Drop for IppRequest {
+	fn drop(&mut self) {
+		// SAFETY: `self.0` is a valid request obtained in `Self::new`, which has not been sent.
+		unsafe { cups::ippDelete(self.0) };
+	}
+}
+
+/// Reads the response to a request sent with [`IppRequest::send_with_data()`], once all of its
+/// data has been written.
+/// Returns `Err` if no response was received, or if the server responded with an error.
+pub fn receive_response(resource: &CStr) -> Result<IppResponse, PrintError> {
+	// SAFETY: `resource` is a valid C string. The returned response is either null, or a valid
+	// pointer that is freed when `IppResponse` is dropped.
+	let response =
+		unsafe { cups::cupsGetResponse(cups::consts::http::CUPS_HTTP_DEFAULT, resource.as_ptr()) };
+	IppResponse::try_from_ptr(response)
+}
+
+// MARK: - Response
+
+/// A struct representing an IPP response received from the CUPS server.
+pub struct IppResponse(*mut cups::ipp_t);
+impl IppResponse {
+	/// Wraps a response received from CUPS, which is freed on drop.
+	/// Returns `Err` if `ptr` is null, or if the status code of the response indicates an error.
+	fn try_from_ptr(ptr: *mut cups::ipp_t) -> Result<Self, PrintError> {
+		if ptr.is_null() {
+			return Err(job::get_last_error());
+		}
+		let response = Self(ptr);
+		if response.is_error() {
+			return Err(job::get_last_error());
+		}
+		Ok(response)
+	}
+	/// Returns `true` if the status code of this response indicates an error.
+	fn is_error(&self) -> bool {
+		// SAFETY: `self.0` is a valid response obtained in `Self::try_from_ptr`.
+		let status = unsafe { cups::ippGetStatusCode(self.0) };
+		is_error_status(status)
+	}
+	/// Returns all attributes of this response, in order.
+	pub fn attributes(&mut self) -> Vec<IppAttribute<'_>> {
+		let mut attributes = Vec::new();
+		// SAFETY: `self.0` is a valid response. The attributes are owned by the response, and
+		// remain valid while it is borrowed.
+		unsafe {
+			let mut ptr = cups::ippFirstAttribute(self.0);
+			while let Some(attribute) = IppAttribute::from_ptr(ptr) {
+				attributes.push(attribute);
+				ptr = cups::ippNextAttribute(self.0);
+			}
+		}
+		attributes
+	}
+	/// Returns the first value of the attribute with the specified name, converted to a string.
+	pub fn find_value(&mut self, name: &str) -> Option<String> {
+		self.attributes()
+			.into_iter()
+			.find(|attribute| attribute.get_name().as_deref() == Some(name))
+			.and_then(|attribute| attribute.values().into_iter().next())
+	}
+}
+impl Drop for IppResponse {
+	fn drop(&mut self) {
+		// SAFETY: `self.0` is a valid response obtained in `Self::try_from_ptr`.
+		unsafe { cups::ippDelete(self.0) };
+	}
+}
+
+/// Returns `true` if `status` indicates an error.
+/// All successful status codes (such as `successful-ok-events-complete`) are below 0x0200, where
+/// the redirection and error codes start.
+fn is_error_status(status: cups::ipp_status_e) -> bool {
+	status.0 >= cups::ipp_status_e::IPP_STATUS_REDIRECTION_OTHER_SITE.0
+}
+
+// MARK: - Attribute
+
+/// A struct representing an IPP attribute, owned by another CUPS structure (for example,
+/// an IPP message or destination information).
+pub struct IppAttribute<'a> {
+	ptr: *mut cups::ipp_attribute_t,
+	marker: PhantomData<&'a cups::ipp_attribute_t>,
+}
+impl<'a> IppAttribute<'a> {
+	/// Wraps a pointer to an attribute. Returns `None` if `ptr` is null.
+	///
+	/// # Safety
+	/// `ptr` must either be null, or point to a valid attribute, which remains valid for the
+	/// lifetime `'a`.
+	pub unsafe fn from_ptr(ptr: *mut cups::ipp_attribute_t) -> Option<Self> {
+		if ptr.is_null() {
+			return None;
+		}
+		Some(Self {
+			ptr,
+			marker: PhantomData,
+		})
+	}
+	/// Returns the name of this attribute.
+	/// Returns `None` for separators between groups of attributes, which have no name.
+	pub fn get_name(&self) -> Option<String> {
+		// SAFETY: `self.ptr` is a valid pointer to an attribute. CUPS returns a valid C string, or
+		// a null pointer.
+		unsafe {
+			let name = cups::ippGetName(self.ptr);
+			if name.is_null() {
+				None
+			} else {
+				Some(util::cstr_to_string(name))
+			}
+		}
+	}
+	/// Returns the group this attribute belongs to (such as operation or job attributes).
+	pub fn get_group(&self) -> cups::ipp_tag_e {
+		// SAFETY: `self.ptr` is a valid pointer to an attribute.
+		unsafe { cups::ippGetGroupTag(self.ptr) }
+	}
+	/// Returns the amount of values of this attribute.
+	pub fn count(&self) -> usize {
+		// SAFETY: `self.ptr` is a valid pointer to an attribute.
+		let count = unsafe { cups::ippGetCount(self.ptr) };
+		count.max(0) as usize
+	}
+	/// Returns the values of this attribute, converted to strings.
+	///
+	/// Integers and enums are converted to their decimal representation, ranges to `lower-upper`,
+	/// and resolutions to `XxYdpi` or `XxYdpcm`. Values of other types that do not have a string
+	/// representation (such as collections) are skipped.
+	pub fn values(&self) -> Vec<String> {
+		(0..self.count() as c_int)
+			.filter_map(|index| self.value(index))
+			.collect()
+	}
+	/// Returns the collection values of this attribute (such as the entries of
+	/// `media-col-database`). Returns an empty vector if the values are not collections.
+	pub fn collections(&self) -> Vec<IppCollection<'a>> {
+		// SAFETY: `self.ptr` is a valid pointer to an attribute.
+		let value_tag = unsafe { cups::ippGetValueTag(self.ptr) };
+		if value_tag != cups::ipp_tag_e::IPP_TAG_BEGIN_COLLECTION {
+			return Vec::new();
+		}
+		(0..self.count() as c_int)
+			// SAFETY: `self.ptr` is a valid pointer to a collection attribute, and `index` is within
+			// the amount of its values. The collections are owned by the attribute, and thus remain
+			// valid for the lifetime `'a`.
+			.filter_map(|index| unsafe {
+				IppCollection::from_ptr(cups::ippGetCollection(self.ptr, index))
+			})
+			.collect()
+	}
+	/// Returns the value of this attribute at `index`, converted to a string.
+	fn value(&self, index: c_int) -> Option<String> {
+		// SAFETY: `self.ptr` is a valid pointer to an attribute, and `index` is within the amount of
+		// its values. CUPS returns a valid C string (or null) for string-based values.
+		unsafe {
+			match cups::ippGetValueTag(self.ptr) {
+				cups::ipp_tag_e::IPP_TAG_INTEGER | cups::ipp_tag_e::IPP_TAG_ENUM => {
+					Some(cups::ippGetInteger(self.ptr, index).to_string())
+				}
+				cups::ipp_tag_e::IPP_TAG_BOOLEAN => {
+					Some((cups::ippGetBoolean(self.ptr, index) != 0).to_string())
+				}
+				cups::ipp_tag_e::IPP_TAG_RANGE => {
+					let mut upper = 0;
+					let lower = cups::ippGetRange(self.ptr, index, &mut upper);
+					Some(format!("{lower}-{upper}"))
+				}
+				cups::ipp_tag_e::IPP_TAG_RESOLUTION => {
+					let mut yres = 0;
+					let mut units = cups::ipp_res_e::IPP_RES_PER_INCH;
+					let xres = cups::ippGetResolution(self.ptr, index, &mut yres, &mut units);
+					let units = if units == cups::ipp_res_e::IPP_RES_PER_CM {
+						"dpcm"
+					} else {
+						"dpi"
+					};
+					Some(format!("{xres}x{yres}{units}"))
+				}
+				_ => {
+					let string = cups::ippGetString(self.ptr, index, ptr::null_mut());
+					if string.is_null() {
+						None
+					} else {
+						Some(util::cstr_to_string(string))
+					}
+				}
+			}
+		}
+	}
+}
+
+// MARK: - Collection
+
+/// A struct representing the value of a collection attribute, owned by that attribute.
+pub struct IppCollection<'a> {
+	ptr: *mut cups::ipp_t,
+	marker: PhantomData<&'a cups::ipp_t>,
+}
+impl<'a> IppCollection<'a> {
+	/// Wraps a pointer to a collection. Returns `None` if `ptr` is null.
+	///
+	/// # Safety
+	/// `ptr` must either be null, or point to a valid collection, which remains valid for the
+	/// lifetime `'a`.
+	unsafe fn from_ptr(ptr: *mut cups::ipp_t) -> Option<Self> {
+		if ptr.is_null() {
+			return None;
+		}
+		Some(Self {
+			ptr,
+			marker: PhantomData,
+		})
+	}
+	/// Returns the member of this collection with the specified name, if any.
+	pub fn find(&self, name: &CStr) -> Option<IppAttribute<'a>> {
+		// SAFETY: `self.ptr` is a valid pointer to a collection, and `name` is a valid C string.
+		// CUPS returns a pointer to a member owned by the collection, or a null pointer.
+		unsafe {
+			IppAttribute::from_ptr(cups::ippFindAttribute(
+				self.ptr,
+				name.as_ptr(),
+				cups::ipp_tag_e::IPP_TAG_ZERO,
+			))
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::print::unix::cups::ipp_status_e;
+
+	#[test]
+	fn if_status_is_successful_then_not_error() {
+		// All successful status codes, including those of notification operations:
+		let statuses = [
+			ipp_status_e::IPP_STATUS_OK,
+			ipp_status_e::IPP_STATUS_OK_IGNORED_OR_SUBSTITUTED,
+			ipp_status_e::IPP_STATUS_OK_CONFLICTING,
+			ipp_status_e::IPP_STATUS_OK_IGNORED_SUBSCRIPTIONS,
+			ipp_status_e::IPP_STATUS_OK_IGNORED_NOTIFICATIONS,
+			ipp_status_e::IPP_STATUS_OK_TOO_MANY_EVENTS,
+			ipp_status_e::IPP_STATUS_OK_EVENTS_COMPLETE,
+		];
+
+		for status in statuses {
+			assert!(
+				!is_error_status(status),
+				"Status {:#06x} should not be an error",
+				status.0
+			);
+		}
+	}
+
+	#[test]
+	fn if_status_is_redirection_or_error_then_error() {
+		// The first redirection and error status codes:
+		let statuses = [
+			ipp_status_e::IPP_STATUS_REDIRECTION_OTHER_SITE,
+			ipp_status_e::IPP_STATUS_ERROR_BAD_REQUEST,
+			ipp_status_e::IPP_STATUS_ERROR_INTERNAL,
+		];
+
+		for status in statuses {
+			assert!(
+				is_error_status(status),
+				"Status {:#06x} should be an error",
+				status.0
+			);
+		}
+	}
+}
@@ -1,6 +1,10 @@
 use crate::error::PrintError;
+use crate::print::Printer;
+use crate::print::printer::Uri;
 use crate::print::unix::cups;
-use crate::print::unix::dest::{CupsDestination, CupsDestinationInfo};
+use crate::print::unix::cups::{ipp_op_e, ipp_tag_e};
+use crate::print::unix::dest::CupsDestination;
+use crate::print::unix::ipp::{self, IppRequest};
 use crate::print::unix::options::CupsOptions;
 use crate::print::util;
 use std::io::BufRead;
@@ -10,50 +14,83 @@ use std::{ffi, io};
 const FILE_BUFFER_SIZE: usize = 65536; // 64 KiB
 
 /// Stores information related to a print job.
-pub struct JobContext<'a> {
-	http: *mut cups::http_t,
+pub struct JobContext {
+	/// The URI of the printer, which is sent with every request.
+	uri: ffi::CString,
+	/// The resource of the printer on the CUPS server, which requests are sent to.
+	resource: ffi::CString,
+	/// The name of the user that requests are made as, or `None` for the current CUPS user.
+	user: Option<ffi::CString>,
 	options: CupsOptions,
-	destination: CupsDestination<'a>,
-	info: CupsDestinationInfo,
+	format: &'static ffi::CStr,
+	compress: bool,
 }
-impl<'a> JobContext<'a> {
+impl JobContext {
 	pub fn new(
-		destination: CupsDestination<'a>,
-		info: CupsDestinationInfo,
+		printer: &Printer,
+		destination: &CupsDestination,
 		options: CupsOptions,
-	) -> Self {
-		Self {
-			http: cups::consts::http::CUPS_HTTP_DEFAULT,
+		user: Option<&str>,
+	) -> Result<Self, PrintError> {
+		// The URI of the destination is preferred, since CUPS updates it when the destination info
+		// is copied (for example, when it creates a queue for a printer found on the network):
+		let uri = match destination.get_option("printer-uri-supported") {
+			Some(uri) => ffi::CString::new(uri)?,
+			None => ipp::get_printer_uri(printer)?,
+		};
+		let resource = Uri::parse(&uri.to_string_lossy())
+			.map(|uri| uri.path)
+			.filter(|path| !path.is_empty())
+			.unwrap_or_else(|| String::from("/"));
+		Ok(Self {
+			uri,
+			resource: ffi::CString::new(resource)?,
+			user: user.map(ffi::CString::new).transpose()?,
 			options,
-			destination,
-			info,
-		}
+			format: cups::consts::format::CUPS_FORMAT_AUTO,
+			compress: false,
+		})
+	}
+	/// Creates a request for the specified operation on the printer, made as the user of the job.
+	fn new_request(&self, operation: ipp_op_e) -> IppRequest {
+		let mut request = match self.user {
+			Some(ref user) => IppRequest::new_as_user(operation, user),
+			None => IppRequest::new(operation),
+		};
+		request.add_string(
+			ipp_tag_e::IPP_TAG_OPERATION,
+			ipp_tag_e::IPP_TAG_URI,
+			c"printer-uri",
+			&self.uri,
+		);
+		request
 	}
 }
 
 /// A struct that represents a CUPS job.
-pub struct CupsJob<'a> {
+pub struct CupsJob {
 	/// The job ID, assigned by CUPS.
 	id: ffi::c_int,
 	/// Title of the job.
 	title: String,
 	/// The context of the print job.
-	context: JobContext<'a>,
+	context: JobContext,
 	/// The amount of submitted documents.
 	amount_documents: usize,
 	/// Flag indicating whether the job should be cancelled when the value is dropped.
 	cancel_on_drop: bool,
 }
-impl<'a> CupsJob<'a> {
-	/// Creates a CUPS job.
-	/// If successful, this will result in a new job on the CUPS server.
+impl CupsJob {
+	/// Creates a CUPS job on the printer, made as the user `user` (or the current CUPS user, if
+	/// `None`). If successful, this will result in a new job on the CUPS server.
 	pub fn try_new(
 		title: &str,
-		dest: CupsDestination<'a>,
-		info: CupsDestinationInfo,
+		printer: &Printer,
+		dest: &CupsDestination,
 		opts: CupsOptions,
+		user: Option<&str>,
 	) -> Result<Self, PrintError> {
-		let mut context = JobContext::new(dest, info, opts);
+		let mut context = JobContext::new(printer, dest, opts, user)?;
 		let job_id = create_job(title, &mut context)?;
 		Ok(Self {
 			id: job_id,
@@ -63,6 +100,20 @@ impl<'a> CupsJob<'a> {
 			cancel_on_drop: true,
 		})
 	}
+	/// Returns the ID of this job, assigned by CUPS.
+	pub fn get_id(&self) -> ffi::c_int {
+		self.id
+	}
+	/// Sets the MIME type of the documents that are added afterwards.
+	/// By default, CUPS detects the type of each document.
+	pub fn set_format(&mut self, format: &'static ffi::CStr) {
+		self.context.format = format;
+	}
+	/// Sets whether the documents that are added afterwards are compressed with gzip while they are
+	/// uploaded. The options of the job must request gzip compression as well.
+	pub fn set_compression(&mut self, compress: bool) {
+		self.context.compress = compress;
+	}
 	/// Adds the contents of each of [`readers`]` as a document to this job.
 	/// Once printing is started by calling [`Self::print()`], all of the added documents
 	/// are printed in the course of this job.
@@ -83,11 +134,35 @@ impl<'a> CupsJob<'a> {
 	/// Once printing is started by calling [`Self::print()`], all of the added documents
 	/// are printed in the course of this job.
 	pub fn add_document<R>(&mut self, reader: R) -> Result<(), PrintError>
+	where
+		R: std::io::Read,
+	{
+		self.upload_document(reader, None)
+	}
+	/// Adds the contents of [`reader`] as a document to this job, using the specified `options`
+	/// for this document instead of the options of the job.
+	pub fn add_document_with_options<R>(
+		&mut self,
+		reader: R,
+		mut options: CupsOptions,
+	) -> Result<(), PrintError>
+	where
+		R: std::io::Read,
+	{
+		self.upload_document(reader, Some(&mut options))
+	}
+	/// Uploads the contents of [`reader`] as a document, using `options` if specified, or the
+	/// options of the job otherwise.
+	fn upload_document<R>(
+		&mut self,
+		reader: R,
+		options: Option<&mut CupsOptions>,
+	) -> Result<(), PrintError>
 	where
 		R: std::io::Read,
 	{
 		let file_name = format!("{}-{}", self.title, self.amount_documents + 1);
-		start_upload(self.id, &file_name, &mut self.context)?;
+		start_upload(self.id, &file_name, options, &mut self.context)?;
 		upload(reader, &self.context)?;
 		finish_upload(&mut self.context)?;
 		self.amount_documents += 1;
@@ -100,7 +175,7 @@ impl<'a> CupsJob<'a> {
 		Ok(())
 	}
 }
-impl<'a> Drop for CupsJob<'a> {
+impl Drop for CupsJob {
 	fn drop(&mut self) {
 		if self.cancel_on_drop {
 			let _ = cancel_job(self.id, &mut self.context)
@@ -112,61 +187,82 @@ impl<'a> Drop for CupsJob<'a> {
 /// Creates a print job.
 fn create_job(title: &str, context: &mut JobContext) -> Result<ffi::c_int, PrintError> {
 	let title = ffi::CString::new(title)?;
-	let mut job_id = 0;
-
-	// SAFETY: `context` contains safe wrappers over CUPS bindings (and `http`, which can be a null
-	// pointer), thus all pointers passed into `cupsCreateDestJob` are valid.
-	let status = unsafe {
-		cups::cupsCreateDestJob(
-			context.http,
-			context.destination.as_mut_ptr(),
-			context.info.as_mut_ptr(),
-			&mut job_id,
-			title.as_ptr(),
-			context.options.as_fat_ptr_mut().size,
-			context.options.as_fat_ptr_mut().ptr,
-		)
-	};
-	if status != cups::ipp_status_e::IPP_STATUS_OK {
-		return Err(get_last_error());
-	}
+	let mut request = context.new_request(ipp_op_e::IPP_OP_CREATE_JOB);
+	request.add_string(
+		ipp_tag_e::IPP_TAG_OPERATION,
+		ipp_tag_e::IPP_TAG_NAME,
+		c"job-name",
+		&title,
+	);
+	request.add_options(&mut context.options, ipp_tag_e::IPP_TAG_OPERATION);
+	request.add_options(&mut context.options, ipp_tag_e::IPP_TAG_JOB);
 
-	Ok(job_id)
+	request
+		.send(&context.resource)?
+		.find_value("job-id")
+		.and_then(|id| id.parse().ok())
+		.ok_or(PrintError::NecessaryInformationMissing(String::from(
+			"no job ID",
+		)))
 }
 
 /// Signals to initiate a file transfer to a specified print job.
 fn start_upload(
 	job_id: ffi::c_int,
 	file_name: &str,
+	options: Option<&mut CupsOptions>,
 	context: &mut JobContext,
 ) -> Result<(), PrintError> {
 	let filename = ffi::CString::new(file_name.as_bytes())?;
-	// SAFETY: `context` contains safe wrappers over CUPS bindings (and http, which can be a null
-	// pointer), thus all pointers passed into `cupsStartDestDocument` are valid.
-	let status = unsafe {
-		cups::cupsStartDestDocument(
-			context.http,
-			context.destination.as_mut_ptr(),
-			context.info.as_mut_ptr(),
-			job_id,
-			filename.as_ptr(),
-			cups::consts::format::CUPS_FORMAT_AUTO.as_ptr(),
-			context.options.as_fat_ptr_mut().size,
-			context.options.as_fat_ptr_mut().ptr,
-			cups::consts::bool(false), // we always pass `false` here & start printing with closeDestJob
-		)
+	let mut request = context.new_request(ipp_op_e::IPP_OP_SEND_DOCUMENT);
+	request.add_integer(
+		ipp_tag_e::IPP_TAG_OPERATION,
+		ipp_tag_e::IPP_TAG_INTEGER,
+		c"job-id",
+		job_id,
+	);
+	request.add_string(
+		ipp_tag_e::IPP_TAG_OPERATION,
+		ipp_tag_e::IPP_TAG_NAME,
+		c"document-name",
+		&filename,
+	);
+	request.add_string(
+		ipp_tag_e::IPP_TAG_OPERATION,
+		ipp_tag_e::IPP_TAG_MIMETYPE,
+		c"document-format",
+		context.format,
+	);
+	// we always pass `false` here & start printing with `close_job`:
+	request.add_boolean(ipp_tag_e::IPP_TAG_OPERATION, c"last-document", false);
+	let options = match options {
+		Some(options) => options,
+		None => &mut context.options,
 	};
-	if status != cups::http_status_e::HTTP_STATUS_CONTINUE {
-		return Err(get_last_error());
-	}
+	request.add_options(options, ipp_tag_e::IPP_TAG_OPERATION);
+	request.add_options(options, ipp_tag_e::IPP_TAG_DOCUMENT);
 
-	Ok(())
+	request.send_with_data(&context.resource)
 }
 
 /// Reads the contents from a specified reader, and transfers them to CUPS.
+/// The contents are compressed with gzip on the fly, if requested by the context.
+fn upload<R>(reader: R, context: &JobContext) -> Result<(), PrintError>
+where
+	R: io::Read,
+{
+	if context.compress {
+		let encoder = flate2::read::GzEncoder::new(reader, flate2::Compression::default());
+		transfer(encoder)
+	} else {
+		transfer(reader)
+	}
+}
+
+/// Reads the contents from a specified reader, and transfers them to CUPS unchanged.
 /// This function wraps the provided [`reader`] in a [`std::io::BufReader`],
 /// thus there is no need to do this at the call site.
-fn upload<R>(reader: R, context: &JobContext) -> Result<(), PrintError>
+fn transfer<R>(reader: R) -> Result<(), PrintError>
 where
 	R: io::Read,
 {
@@ -179,10 +275,16 @@ where
 			break;
 		}
 
-		// SAFETY: `http` can be any pointer, `buf` is the just readed into buffer and `buf_len` its
-		// length, thus `cupsWriteRequestData` arguments are correct.
-		let status =
-			unsafe { cups::cupsWriteRequestData(context.http, buf.as_ptr() as *const _, buf_len) };
+		// SAFETY: the default connection is the one the document request was sent on, `buf` is the
+		// just readed into buffer and `buf_len` its length, thus `cupsWriteRequestData` arguments
+		// are correct.
+		let status = unsafe {
+			cups::cupsWriteRequestData(
+				cups::consts::http::CUPS_HTTP_DEFAULT,
+				buf.as_ptr() as *const _,
+				buf_len,
+			)
+		};
 		if status != cups::http_status_e::HTTP_STATUS_CONTINUE {
 			return Err(get_last_error());
 		}
@@ -195,54 +297,39 @@ where
 
 /// Signals that the file transfer has finished.
 fn finish_upload(context: &mut JobContext) -> Result<(), PrintError> {
-	// SAFETY: `context` contains safe wrappers over CUPS bindings (and `http`, which can be a null
-	// pointer), and thus all pointers passed into `cupsFinishDestDocument` are safe.
-	let status = unsafe {
-		cups::cupsFinishDestDocument(
-			context.http,
-			context.destination.as_mut_ptr(),
-			context.info.as_mut_ptr(),
-		)
-	};
-	if status != cups::ipp_status_e::IPP_STATUS_OK {
-		return Err(get_last_error());
-	}
+	ipp::receive_response(&context.resource)?;
 	Ok(())
 }
 
 /// Cancels the job with the specified ID.
 fn cancel_job(job_id: ffi::c_int, context: &mut JobContext) -> Result<(), PrintError> {
-	// SAFETY: `context` contains safe wrappers over CUPS bindings (and `http`, which can be a null
-	// pointer), and thus all pointers passed into `cupsCancelDestJob` are safe.
-	let status =
-		unsafe { cups::cupsCancelDestJob(context.http, context.destination.as_mut_ptr(), job_id) };
-	if status != cups::ipp_status_e::IPP_STATUS_OK {
-		return Err(get_last_error());
-	}
+	let mut request = context.new_request(ipp_op_e::IPP_OP_CANCEL_JOB);
+	request.add_integer(
+		ipp_tag_e::IPP_TAG_OPERATION,
+		ipp_tag_e::IPP_TAG_INTEGER,
+		c"job-id",
+		job_id,
+	);
+	request.send(&context.resource)?;
 	Ok(())
 }
 
 /// Closes the job with the specified ID and starts printing.
 fn close_job(job_id: ffi::c_int, context: &mut JobContext) -> Result<(), PrintError> {
-	// SAFETY: `context` contains safe wrappers over CUPS bindings (and `http`, which can be a null
-	// pointer), and thus all pointers passed into `cupsCloseDestJob` are safe.
-	let status = unsafe {
-		cups::cupsCloseDestJob(
-			context.http,
-			context.destination.as_mut_ptr(),
-			context.info.as_mut_ptr(),
-			job_id,
-		)
-	};
-	if status != cups::ipp_status_e::IPP_STATUS_OK {
-		return Err(get_last_error());
-	}
+	let mut request = context.new_request(ipp_op_e::IPP_OP_CLOSE_JOB);
+	request.add_integer(
+		ipp_tag_e::IPP_TAG_OPERATION,
+		ipp_tag_e::IPP_TAG_INTEGER,
+		c"job-id",
+		job_id,
+	);
+	request.send(&context.resource)?;
 	Ok(())
 }
 
 /// Retrieves the last error string from CUPS and constructs a [`PrintError::Backend`].
 /// If no error string is returned by CUPS, an empty error string is used.
-fn get_last_error() -> PrintError {
+pub fn get_last_error() -> PrintError {
 	// SAFETY: `cupsLastErrorString` accepts no arguments.
 	let message = unsafe {
 		let ptr = cups::cupsLastErrorString();
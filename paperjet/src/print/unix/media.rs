@@ -0,0 +1,220 @@
+use std::ffi::{CStr, CString, c_int};
+
+use crate::error::PrintError;
+use crate::print::Printer;
+use crate::print::unix::cups::consts::{media, opts};
+use crate::print::unix::cups::{ipp_op_e, ipp_tag_e};
+use crate::print::unix::dest::{CupsDestination, CupsDestinationInfo};
+use crate::print::unix::ipp::{IppCollection, IppRequest, get_printer_uri};
+use crate::print::unix::options::CupsOptions;
+
+/// The resource that printer attributes are requested from.
+const RESOURCE: &CStr = c"/";
+
+/// The difference (in hundredths of millimeters) up to which media sizes are considered equal,
+/// since sizes converted from inches may be rounded differently.
+const SIZE_TOLERANCE: c_int = 100;
+
+/// Replaces the media options in `cups_options` with a `media-col` collection that specifies
+/// a borderless media (with zero margins), as listed in the media database of the destination.
+///
+/// The media size is taken from the `media` option (or the default media, if not specified), and
+/// the media source and type are kept. Returns `false` and leaves `cups_options` unchanged if the
+/// destination does not support borderless printing on this media size, or if a media type is
+/// specified and the media database of the printer (`media-col-database`) lists no borderless
+/// media of this size and type.
+pub fn apply_borderless(
+	cups_options: &mut CupsOptions,
+	printer: &Printer,
+	destination: &mut CupsDestination,
+	info: &mut CupsDestinationInfo,
+) -> Result<bool, PrintError> {
+	let pairs = cups_options.to_pairs();
+	let find = |name: &CStr| {
+		pairs
+			.iter()
+			.find(|(key, _)| key.as_bytes() == name.to_bytes())
+			.map(|(_, value)| value.as_str())
+	};
+
+	let media = find(opts::CUPS_MEDIA).map(CString::new).transpose()?;
+	let Some(size) = info.get_media_size(
+		destination,
+		media.as_deref(),
+		media::CUPS_MEDIA_FLAGS_BORDERLESS,
+	) else {
+		return Ok(false);
+	};
+	if size.top != 0 || size.bottom != 0 || size.left != 0 || size.right != 0 {
+		return Ok(false);
+	}
+	let media_type = find(opts::CUPS_MEDIA_TYPE);
+	if let Some(media_type) = media_type {
+		let is_listed = get_media_database(printer)?
+			.iter()
+			.any(|entry| entry.is_borderless_match((size.width, size.length), media_type));
+		if !is_listed {
+			return Ok(false);
+		}
+	}
+	let media_col = format_media_col(
+		(size.width, size.length),
+		find(opts::CUPS_MEDIA_SOURCE),
+		media_type,
+	);
+	let media_col = CString::new(media_col)?;
+
+	// `media-col` supersedes these, and some printers reject jobs that specify both:
+	cups_options.remove(opts::CUPS_MEDIA);
+	cups_options.remove(opts::CUPS_MEDIA_SOURCE);
+	cups_options.remove(opts::CUPS_MEDIA_TYPE);
+	cups_options.add_raw(opts::CUPS_MEDIA_COL, &media_col);
+	Ok(true)
+}
+
+/// An entry of the media database of a printer (`media-col-database`).
+#[derive(Debug)]
+struct MediaEntry {
+	/// The width and length of the media, in hundredths of millimeters.
+	size: (c_int, c_int),
+	/// The top, bottom, left and right margins, in hundredths of millimeters.
+	margins: [c_int; 4],
+	/// The type of the media (such as `photographic-glossy`), if specified.
+	media_type: Option<String>,
+}
+impl MediaEntry {
+	/// Reads an entry from a `media-col` collection.
+	/// Returns `None` if the collection does not specify the size and margins of the media.
+	fn from_collection(collection: &IppCollection) -> Option<Self> {
+		let integer = |collection: &IppCollection, name: &CStr| {
+			collection
+				.find(name)?
+				.values()
+				.first()?
+				.parse::<c_int>()
+				.ok()
+		};
+		let media_size = collection.find(c"media-size")?.collections();
+		let media_size = media_size.first()?;
+		Some(Self {
+			size: (
+				integer(media_size, c"x-dimension")?,
+				integer(media_size, c"y-dimension")?,
+			),
+			margins: [
+				integer(collection, c"media-top-margin")?,
+				integer(collection, c"media-bottom-margin")?,
+				integer(collection, c"media-left-margin")?,
+				integer(collection, c"media-right-margin")?,
+			],
+			media_type: collection
+				.find(c"media-type")
+				.and_then(|attribute| attribute.values().into_iter().next()),
+		})
+	}
+	/// Returns `true` if this entry is a borderless media of the specified size and type.
+	fn is_borderless_match(&self, (width, length): (c_int, c_int), media_type: &str) -> bool {
+		self.margins == [0; 4]
+			&& (self.size.0 - width).abs() <= SIZE_TOLERANCE
+			&& (self.size.1 - length).abs() <= SIZE_TOLERANCE
+			&& self.media_type.as_deref() == Some(media_type)
+	}
+}
+
+/// Requests the media database (`media-col-database`) of the printer.
+/// Returns an empty vector if the printer does not report one.
+fn get_media_database(printer: &Printer) -> Result<Vec<MediaEntry>, PrintError> {
+	let mut request = IppRequest::new(ipp_op_e::IPP_OP_GET_PRINTER_ATTRIBUTES);
+	request.add_string(
+		ipp_tag_e::IPP_TAG_OPERATION,
+		ipp_tag_e::IPP_TAG_URI,
+		c"printer-uri",
+		&get_printer_uri(printer)?,
+	);
+	request.add_string(
+		ipp_tag_e::IPP_TAG_OPERATION,
+		ipp_tag_e::IPP_TAG_KEYWORD,
+		c"requested-attributes",
+		c"media-col-database",
+	);
+
+	let mut response = request.send(RESOURCE)?;
+	let entries = response
+		.attributes()
+		.iter()
+		.filter(|attribute| attribute.get_name().as_deref() == Some("media-col-database"))
+		.flat_map(|attribute| attribute.collections())
+		.filter_map(|collection| MediaEntry::from_collection(&collection))
+		.collect();
+	Ok(entries)
+}
+
+/// Formats a `media-col` collection with zero margins.
+/// The dimensions of the media are specified in hundredths of millimeters.
+fn format_media_col(
+	(width, length): (c_int, c_int),
+	source: Option<&str>,
+	media_type: Option<&str>,
+) -> String {
+	let mut members = vec![
+		format!("media-size={{x-dimension={width} y-dimension={length}}}"),
+		String::from("media-top-margin=0"),
+		String::from("media-bottom-margin=0"),
+		String::from("media-left-margin=0"),
+		String::from("media-right-margin=0"),
+	];
+	if let Some(source) = source {
+		members.push(format!("media-source={source}"));
+	}
+	if let Some(media_type) = media_type {
+		members.push(format!("media-type={media_type}"));
+	}
+	format!("{{{}}}", members.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn if_media_col_is_formatted_then_margins_are_zero() {
+		// A media with a source, but no type:
+		let media_col = format_media_col((21000, 29700), Some("main"), None);
+
+		// The collection should have the size, zero margins and the source:
+		assert_eq!(
+			media_col,
+			"{media-size={x-dimension=21000 y-dimension=29700} media-top-margin=0 \
+			media-bottom-margin=0 media-left-margin=0 media-right-margin=0 media-source=main}",
+			"Unexpected media-col: {media_col}"
+		);
+	}
+
+	#[test]
+	fn if_media_entry_has_other_type_or_margins_then_not_borderless_match() {
+		// A borderless glossy A4 entry:
+		let entry = MediaEntry {
+			size: (21000, 29700),
+			margins: [0; 4],
+			media_type: Some(String::from("photographic-glossy")),
+		};
+		assert!(
+			entry.is_borderless_match((21000, 29700), "photographic-glossy"),
+			"Entry of the same size and type should match, but didn't"
+		);
+		assert!(
+			!entry.is_borderless_match((21000, 29700), "stationery"),
+			"Entry of another type should not match, but did"
+		);
+
+		// The same entry with margins:
+		let entry = MediaEntry {
+			margins: [300, 300, 300, 300],
+			..entry
+		};
+		assert!(
+			!entry.is_borderless_match((21000, 29700), "photographic-glossy"),
+			"Entry with margins should not match, but did"
+		);
+	}
+}
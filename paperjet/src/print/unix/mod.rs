@@ -1,5 +1,10 @@
+pub mod conflicts;
 pub mod cups;
 pub mod dest;
+pub mod ipp;
 pub mod job;
+pub mod media;
 pub mod native;
 pub mod options;
+pub mod queue;
+pub mod subscription;
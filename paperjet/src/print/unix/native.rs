@@ -1,15 +1,24 @@
 use std::collections::HashMap;
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::io::Read;
 use std::slice;
 
+use crate::capabilities::Capabilities;
 use crate::error::PrintError;
-use crate::options::PrintOptions;
-use crate::print::unix::cups;
+use crate::events::{Event, EventKind};
+use crate::options::{DocumentOptions, JobPassword, OptionPolicy, PrintOption, PrintOptions};
+use crate::print::unix::cups::consts::opts;
 use crate::print::unix::dest::{CupsDestination, CupsDestinationInfo, CupsDestinations};
 use crate::print::unix::job::CupsJob;
-use crate::print::unix::options::{CupsOption, CupsOptions};
-use crate::print::{CrossPlatformApi, PlatformSpecificApi, Printer, util};
+use crate::print::unix::options::{
+	CupsOption, CupsOptionVisitor, CupsOptions, check_option_value, visit_document_options,
+	visit_options,
+};
+use crate::print::unix::{conflicts, cups, media, options, queue, subscription};
+use crate::print::{CrossPlatformApi, PlatformSpecificApi, Printer, SubmittedJob, util};
+use crate::validation::{
+	ConflictResolution, OptionSubstitution, UnsupportedOption, ValidationReport,
+};
 
 impl CrossPlatformApi for PlatformSpecificApi {
 	fn get_printers() -> Vec<Printer> {
@@ -28,65 +37,542 @@ impl CrossPlatformApi for PlatformSpecificApi {
 		CupsDestination::new_default().map(map_dest_to_printer)
 	}
 
-	fn print<I, R>(readers: I, printer: Printer, options: PrintOptions) -> Result<(), PrintError>
+	fn print<I, R>(
+		readers: I,
+		printer: Printer,
+		options: PrintOptions,
+	) -> Result<SubmittedJob, PrintError>
 	where
 		I: IntoIterator<Item = R>,
 		R: Read,
 	{
-		let id = CString::new(printer.identifier.clone())?;
-		let mut cups_dest = CupsDestination::new_by_name(&id)
-			.ok_or(PrintError::PrinterNotFound(printer.identifier))?;
+		let (mut cups_dest, mut cups_info) = get_destination(&printer)?;
+		let (mut cups_opts, mut substitutions) =
+			add_options(&options, &mut cups_dest, &mut cups_info)?;
+		substitutions.extend(add_borderless(
+			&mut cups_opts,
+			&options,
+			&printer,
+			&mut cups_dest,
+			&mut cups_info,
+		)?);
+		let compress = is_compressed(&cups_opts);
+		let mut cups_job = CupsJob::try_new(
+			"paperjet",
+			&printer,
+			&cups_dest,
+			cups_opts,
+			options.requesting_user_name.as_deref(),
+		)?;
+		cups_job.set_compression(compress);
+
+		let id = cups_job.get_id();
+		cups_job.add_documents(readers)?;
+		cups_job.print()?;
+		Ok(SubmittedJob { id, substitutions })
+	}
+
+	fn print_documents<I, R>(
+		documents: I,
+		printer: Printer,
+		options: PrintOptions,
+	) -> Result<SubmittedJob, PrintError>
+	where
+		I: IntoIterator<Item = (R, DocumentOptions)>,
+		R: Read,
+	{
+		let (mut cups_dest, mut cups_info) = get_destination(&printer)?;
+		let (mut cups_opts, mut substitutions) =
+			add_options(&options, &mut cups_dest, &mut cups_info)?;
 
-		let mut cups_info = CupsDestinationInfo::new(&mut cups_dest).ok_or(
-			PrintError::NecessaryInformationMissing(String::from("no CUPS destination info")),
+		// Validate the options of all documents before creating the job:
+		let mut documents_with_opts = Vec::new();
+		for (reader, doc_options) in documents {
+			let (doc_opts, doc_substitutions) = collect_validated(
+				cups_opts.try_clone()?,
+				options.policy.unwrap_or_default(),
+				&mut cups_dest,
+				&mut cups_info,
+				|collector| visit_document_options(&doc_options, collector),
+			)?;
+			substitutions.extend(doc_substitutions);
+			documents_with_opts.push((reader, doc_opts));
+		}
+		// Documents can only be printed with different options if the printer handles them
+		// separately:
+		let has_document_options = documents_with_opts
+			.iter()
+			.any(|(_, doc_opts)| doc_opts.to_pairs() != cups_opts.to_pairs());
+		if has_document_options {
+			let handling = cups_opts
+				.get(&opts::CUPS_MULTIPLE_DOCUMENT_HANDLING.to_string_lossy())
+				.map(CString::new)
+				.transpose()?
+				.unwrap_or_else(|| {
+					opts::values::CUPS_MULTIPLE_DOCUMENT_HANDLING_UNCOLLATED.to_owned()
+				});
+			let is_supported = CupsOptions::validate_raw(
+				&mut cups_dest,
+				&mut cups_info,
+				opts::CUPS_MULTIPLE_DOCUMENT_HANDLING,
+				&handling,
+			);
+			if !is_supported {
+				let handling = handling.to_string_lossy().into_owned();
+				if matches!(options.policy.unwrap_or_default(), OptionPolicy::Strict) {
+					return Err(PrintError::UnsupportedOption {
+						name: opts::CUPS_MULTIPLE_DOCUMENT_HANDLING
+							.to_string_lossy()
+							.into_owned(),
+						value: handling,
+					});
+				}
+				// the options of the job are used for all documents instead:
+				for (_, doc_opts) in &mut documents_with_opts {
+					*doc_opts = cups_opts.try_clone()?;
+				}
+				substitutions.push(OptionSubstitution {
+					name: opts::CUPS_MULTIPLE_DOCUMENT_HANDLING
+						.to_string_lossy()
+						.into_owned(),
+					requested: handling,
+					substitute: None,
+				});
+			}
+		}
+		// Borderless printing replaces the media options, so it is applied once the media options
+		// of the documents have been added:
+		substitutions.extend(add_borderless(
+			&mut cups_opts,
+			&options,
+			&printer,
+			&mut cups_dest,
+			&mut cups_info,
+		)?);
+		for (_, doc_opts) in &mut documents_with_opts {
+			let substitution =
+				add_borderless(doc_opts, &options, &printer, &mut cups_dest, &mut cups_info)?;
+			if let Some(substitution) = substitution
+				&& !substitutions.iter().any(|s| s.name == substitution.name)
+			{
+				substitutions.push(substitution);
+			}
+		}
+
+		let compress = is_compressed(&cups_opts);
+		let mut cups_job = CupsJob::try_new(
+			"paperjet",
+			&printer,
+			&cups_dest,
+			cups_opts,
+			options.requesting_user_name.as_deref(),
 		)?;
-		let cups_opts = add_options(options, &mut cups_dest, &mut cups_info)?;
-		let mut cups_job = CupsJob::try_new("paperjet", cups_dest, cups_info, cups_opts)?;
+		cups_job.set_compression(compress);
+		let id = cups_job.get_id();
+		for (reader, doc_opts) in documents_with_opts {
+			cups_job.add_document_with_options(reader, doc_opts)?;
+		}
+		cups_job.print()?;
+		Ok(SubmittedJob { id, substitutions })
+	}
 
+	fn print_raw<I, R>(readers: I, printer: Printer) -> Result<SubmittedJob, PrintError>
+	where
+		I: IntoIterator<Item = R>,
+		R: Read,
+	{
+		let (cups_dest, _cups_info) = get_destination(&printer)?;
+		let mut cups_job =
+			CupsJob::try_new("paperjet", &printer, &cups_dest, CupsOptions::new(), None)?;
+		cups_job.set_format(cups::consts::format::CUPS_FORMAT_RAW);
+
+		let id = cups_job.get_id();
 		cups_job.add_documents(readers)?;
-		cups_job.print()
+		cups_job.print()?;
+		Ok(SubmittedJob {
+			id,
+			substitutions: Vec::new(),
+		})
+	}
+
+	fn resolve_conflicts(
+		printer: &Printer,
+		options: &PrintOptions,
+	) -> Result<ConflictResolution, PrintError> {
+		let (mut cups_dest, mut cups_info) = get_destination(printer)?;
+		conflicts::resolve_conflicts(&mut cups_dest, &mut cups_info, options)
+	}
+
+	fn validate(printer: &Printer, options: &PrintOptions) -> Result<ValidationReport, PrintError> {
+		let (mut cups_dest, mut cups_info) = get_destination(printer)?;
+		let mut collector = ReportCollector {
+			report: ValidationReport::default(),
+			cups_destination: &mut cups_dest,
+			cups_info: &mut cups_info,
+		};
+		visit_options(options, &mut collector)?;
+		let mut report = collector.report;
+
+		for (name, value) in &options.extra {
+			let (c_name, c_value) = (CString::new(name.as_str())?, CString::new(value.as_str())?);
+			if !is_extra_option_supported(&mut cups_dest, &mut cups_info, &c_name, &c_value) {
+				let supported = cups_info.get_supported_values(&mut cups_dest, &c_name);
+				report.unsupported.push(UnsupportedOption {
+					name: name.clone(),
+					value: value.clone(),
+					supported,
+					reason: None,
+				});
+			}
+		}
+
+		if let Some(password) = find_too_long_job_password(options, &mut cups_dest, &mut cups_info)
+		{
+			report.unsupported.push(UnsupportedOption {
+				name: JobPassword::get_name().to_lowercase(),
+				value: password.to_human_string(),
+				supported: Vec::new(),
+				reason: None,
+			});
+		}
+
+		let mut cups_opts = CupsOptions::new();
+		visit_options(options, &mut cups_opts)?;
+		if options.borderless.unwrap_or_default()
+			&& !media::apply_borderless(&mut cups_opts, printer, &mut cups_dest, &mut cups_info)?
+		{
+			report.unsupported.push(UnsupportedOption {
+				name: String::from("borderless"),
+				value: String::from("true"),
+				supported: Vec::new(),
+				reason: None,
+			});
+		}
+		Ok(report)
+	}
+
+	fn get_capabilities(printer: &Printer) -> Result<Capabilities, PrintError> {
+		let (mut cups_dest, mut cups_info) = get_destination(printer)?;
+		let job_password_max_length = get_job_password_max_length(&mut cups_dest, &mut cups_info);
+		let job_password_encryptions =
+			cups_info.get_supported_values(&mut cups_dest, opts::CUPS_JOB_PASSWORD_ENCRYPTION);
+		Ok(Capabilities {
+			job_password_max_length,
+			job_password_encryptions,
+		})
+	}
+
+	fn parse_cups_options(options: &str) -> Result<PrintOptions, PrintError> {
+		options::parse_cups_options(options)
+	}
+
+	fn format_cups_options(options: &PrintOptions) -> String {
+		options::format_cups_options(options)
+	}
+
+	fn move_job(job_id: i32, from: &Printer, to: &Printer) -> Result<(), PrintError> {
+		queue::move_jobs(Some(job_id), from, to)
+	}
+
+	fn move_all_jobs(from: &Printer, to: &Printer) -> Result<(), PrintError> {
+		queue::move_jobs(None, from, to)
+	}
+
+	fn restart_job(job_id: i32) -> Result<(), PrintError> {
+		queue::restart_job(job_id)
+	}
+
+	fn create_subscription(
+		printer: Option<&Printer>,
+		events: &[EventKind],
+	) -> Result<i32, PrintError> {
+		subscription::create_subscription(printer, events)
+	}
+
+	fn get_notifications(subscription: i32, first: i32) -> Result<Vec<Event>, PrintError> {
+		subscription::get_notifications(subscription, first)
+	}
+
+	fn cancel_subscription(subscription: i32) -> Result<(), PrintError> {
+		subscription::cancel_subscription(subscription)
 	}
 }
 
+/// Retrieves the CUPS destination corresponding to `printer`, along with its information.
+fn get_destination<'a>(
+	printer: &Printer,
+) -> Result<(CupsDestination<'a>, CupsDestinationInfo), PrintError> {
+	let id = CString::new(printer.identifier.clone())?;
+	let mut cups_dest = CupsDestination::new_by_name(&id)
+		.ok_or_else(|| PrintError::PrinterNotFound(printer.identifier.clone()))?;
+
+	let cups_info = CupsDestinationInfo::new(&mut cups_dest).ok_or(
+		PrintError::NecessaryInformationMissing(String::from("no CUPS destination info")),
+	)?;
+	Ok((cups_dest, cups_info))
+}
+
+/// Validates each of the `options` with the destination, and adds them to a new list of
+/// CUPS options. Unsupported options are handled according to the options' policy.
+///
+/// Returns the CUPS options, along with the changes made to unsupported options.
 fn add_options(
-	options: PrintOptions,
+	options: &PrintOptions,
+	destination: &mut CupsDestination,
+	info: &mut CupsDestinationInfo,
+) -> Result<(CupsOptions, Vec<OptionSubstitution>), PrintError> {
+	// The password is never dropped, since the job would not be held otherwise:
+	if let Some(password) = find_too_long_job_password(options, destination, info) {
+		return Err(PrintError::UnsupportedOption {
+			name: JobPassword::get_name().to_lowercase(),
+			value: password.to_human_string(),
+		});
+	}
+	let policy = options.policy.unwrap_or_default();
+	let (mut cups_options, mut substitutions) =
+		collect_validated(CupsOptions::new(), policy, destination, info, |collector| {
+			visit_options(options, collector)
+		})?;
+	substitutions.extend(add_extra_options(
+		&mut cups_options,
+		options,
+		policy,
+		destination,
+		info,
+	)?);
+	Ok((cups_options, substitutions))
+}
+
+/// Replaces the media options in `cups_options` with a borderless `media-col` collection, if
+/// [`PrintOptions::borderless`] is enabled. If the destination does not support borderless
+/// printing on the media, this is handled according to the options' policy.
+///
+/// Returns the change made to the option, if it is unsupported.
+fn add_borderless(
+	cups_options: &mut CupsOptions,
+	options: &PrintOptions,
+	printer: &Printer,
 	destination: &mut CupsDestination,
 	info: &mut CupsDestinationInfo,
-) -> Result<CupsOptions, PrintError> {
-	// Maybe add a macro for this monstrosity?
-	let mut cups_options = CupsOptions::new();
-	add_option(options.copies, &mut cups_options, destination, info)?;
-	add_option(options.finishings, &mut cups_options, destination, info)?;
-	add_option(options.media_size, &mut cups_options, destination, info)?;
-	add_option(options.media_source, &mut cups_options, destination, info)?;
-	add_option(options.media_type, &mut cups_options, destination, info)?;
-	add_option(options.number_up, &mut cups_options, destination, info)?;
-	add_option(options.orientation, &mut cups_options, destination, info)?;
-	add_option(options.color_mode, &mut cups_options, destination, info)?;
-	add_option(options.quality, &mut cups_options, destination, info)?;
-	add_option(options.sides_mode, &mut cups_options, destination, info)?;
-	Ok(cups_options)
-}
-
-fn add_option<O: CupsOption>(
-	option: Option<O>,
+) -> Result<Option<OptionSubstitution>, PrintError> {
+	if !options.borderless.unwrap_or_default()
+		|| media::apply_borderless(cups_options, printer, destination, info)?
+	{
+		return Ok(None);
+	}
+	// unsupported:
+	if matches!(options.policy.unwrap_or_default(), OptionPolicy::Strict) {
+		return Err(PrintError::UnsupportedOption {
+			name: String::from("borderless"),
+			value: String::from("true"),
+		});
+	}
+	Ok(Some(OptionSubstitution {
+		name: String::from("borderless"),
+		requested: String::from("true"),
+		substitute: None,
+	}))
+}
+
+/// Validates the extra options in `options` with the destination, and adds them to
+/// `cups_options`. Unsupported options are handled according to `policy`; since they have no
+/// fallbacks, they are removed unless the policy is strict. Extra options that are already set
+/// in `cups_options` (by a field of `options`) are replaced by the field's value.
+///
+/// Returns the changes made to unsupported options.
+fn add_extra_options(
 	cups_options: &mut CupsOptions,
-	cups_destination: &mut CupsDestination,
-	cups_info: &mut CupsDestinationInfo,
-) -> Result<(), PrintError> {
-	let Some(option) = option else {
-		return Ok(());
+	options: &PrintOptions,
+	policy: OptionPolicy,
+	destination: &mut CupsDestination,
+	info: &mut CupsDestinationInfo,
+) -> Result<Vec<OptionSubstitution>, PrintError> {
+	let mut substitutions = Vec::new();
+	for (name, value) in &options.extra {
+		// options set by a field take precedence:
+		if let Some(field_value) = cups_options.get(name) {
+			substitutions.push(OptionSubstitution {
+				name: name.clone(),
+				requested: mask_value(name, value),
+				substitute: Some(mask_value(name, &field_value)),
+			});
+			continue;
+		}
+		let (c_name, c_value) = (CString::new(name.as_str())?, CString::new(value.as_str())?);
+		if is_extra_option_supported(destination, info, &c_name, &c_value) {
+			cups_options.add_raw(&c_name, &c_value);
+			continue;
+		}
+		// unsupported:
+		if matches!(policy, OptionPolicy::Strict) {
+			return Err(PrintError::UnsupportedOption {
+				name: name.clone(),
+				value: mask_value(name, value),
+			});
+		}
+		substitutions.push(OptionSubstitution {
+			name: name.clone(),
+			requested: mask_value(name, value),
+			substitute: None,
+		});
+	}
+	Ok(substitutions)
+}
+
+/// Returns `value` for reporting, masked if it is the value of the job password.
+fn mask_value(name: &str, value: &str) -> String {
+	if name.as_bytes() == opts::CUPS_JOB_PASSWORD.to_bytes() {
+		return JobPassword::new(value).to_human_string();
+	}
+	value.to_owned()
+}
+
+/// Returns `true` if the destination supports the option with the specified name and value, or
+/// if it does not advertise the values it supports (as is the case for most vendor options).
+fn is_extra_option_supported(
+	destination: &mut CupsDestination,
+	info: &mut CupsDestinationInfo,
+	name: &CStr,
+	value: &CStr,
+) -> bool {
+	let is_advertised = !info.get_supported_values(destination, name).is_empty();
+	!is_advertised || CupsOptions::validate_raw(destination, info, name, value)
+}
+
+/// Returns the maximum length of a job password, as advertised in `job-password-supported`, or
+/// `None` if the destination does not support job passwords.
+fn get_job_password_max_length(
+	destination: &mut CupsDestination,
+	info: &mut CupsDestinationInfo,
+) -> Option<usize> {
+	info.get_supported_values(destination, opts::CUPS_JOB_PASSWORD)
+		.first()
+		.and_then(|value| value.parse::<usize>().ok())
+		.filter(|length| *length > 0)
+}
+
+/// Returns the job password in `options` if it is longer than the destination supports, or
+/// `None` otherwise (including if the maximum length is not advertised).
+fn find_too_long_job_password<'a>(
+	options: &'a PrintOptions,
+	destination: &mut CupsDestination,
+	info: &mut CupsDestinationInfo,
+) -> Option<&'a JobPassword> {
+	let password = options.job_password.as_ref()?;
+	let max_length = get_job_password_max_length(destination, info)?;
+	(password.expose().chars().count() > max_length).then_some(password)
+}
+
+/// Returns `true` if `cups_options` request documents to be compressed with gzip.
+fn is_compressed(cups_options: &CupsOptions) -> bool {
+	cups_options.to_pairs().iter().any(|(name, value)| {
+		name.as_bytes() == opts::CUPS_COMPRESSION.to_bytes()
+			&& value.as_bytes() == opts::values::CUPS_COMPRESSION_GZIP.to_bytes()
+	})
+}
+
+/// Validates the options visited by `visit` with the destination, and adds them to
+/// `cups_options`. Unsupported options are handled according to `policy`.
+///
+/// Returns the CUPS options, along with the changes made to unsupported options.
+fn collect_validated<F>(
+	cups_options: CupsOptions,
+	policy: OptionPolicy,
+	destination: &mut CupsDestination,
+	info: &mut CupsDestinationInfo,
+	visit: F,
+) -> Result<(CupsOptions, Vec<OptionSubstitution>), PrintError>
+where
+	F: FnOnce(&mut ValidatingCollector) -> Result<(), PrintError>,
+{
+	let mut collector = ValidatingCollector {
+		cups_options,
+		cups_destination: destination,
+		cups_info: info,
+		policy,
+		substitutions: Vec::new(),
 	};
-	// validate:
-	if !cups_options.validate(cups_destination, cups_info, &option) {
-		return Err(PrintError::UnsupportedOption {
+	visit(&mut collector)?;
+	Ok((collector.cups_options, collector.substitutions))
+}
+
+/// Collects visited options into a list of CUPS options, after validating them.
+struct ValidatingCollector<'a, 'b> {
+	cups_options: CupsOptions,
+	cups_destination: &'a mut CupsDestination<'b>,
+	cups_info: &'a mut CupsDestinationInfo,
+	policy: OptionPolicy,
+	substitutions: Vec<OptionSubstitution>,
+}
+impl CupsOptionVisitor for ValidatingCollector<'_, '_> {
+	fn visit<O: CupsOption>(&mut self, option: &O) -> Result<(), PrintError> {
+		check_option_value(option)?;
+		// validate & add:
+		if CupsOptions::validate(self.cups_destination, self.cups_info, option) {
+			self.cups_options.add(option);
+			return Ok(());
+		}
+		// unsupported:
+		let substitute = match self.policy {
+			OptionPolicy::Strict => {
+				return Err(PrintError::UnsupportedOption {
+					name: O::get_name().to_lowercase(),
+					value: option.to_human_string(),
+				});
+			}
+			OptionPolicy::DropUnsupported => None,
+			OptionPolicy::NearestSupported => option.get_fallbacks().into_iter().find(|fallback| {
+				CupsOptions::validate(self.cups_destination, self.cups_info, fallback)
+			}),
+		};
+		if let Some(ref substitute) = substitute {
+			self.cups_options.add(substitute);
+		}
+		self.substitutions.push(OptionSubstitution {
+			name: O::get_name().to_lowercase(),
+			requested: option.to_human_string(),
+			substitute: substitute.map(|value| value.to_human_string()),
+		});
+		Ok(())
+	}
+}
+
+/// Validates visited options, and collects the unsupported ones into a report.
+struct ReportCollector<'a, 'b> {
+	report: ValidationReport,
+	cups_destination: &'a mut CupsDestination<'b>,
+	cups_info: &'a mut CupsDestinationInfo,
+}
+impl CupsOptionVisitor for ReportCollector<'_, '_> {
+	fn visit<O: CupsOption>(&mut self, option: &O) -> Result<(), PrintError> {
+		// invalid regardless of the printer:
+		if let Err(reason) = option.check_value() {
+			self.report.unsupported.push(UnsupportedOption {
+				name: O::get_name().to_lowercase(),
+				value: option.to_human_string(),
+				supported: Vec::new(),
+				reason: Some(reason.to_owned()),
+			});
+			return Ok(());
+		}
+		if CupsOptions::validate(self.cups_destination, self.cups_info, option) {
+			return Ok(());
+		}
+		let name = option
+			.get_cups_supported_pairs()
+			.first()
+			.map_or(O::get_cups_option_name(), |(name, _)| *name);
+		let supported = self
+			.cups_info
+			.get_supported_values(self.cups_destination, name);
+		self.report.unsupported.push(UnsupportedOption {
 			name: O::get_name().to_lowercase(),
 			value: option.to_human_string(),
+			supported,
+			reason: None,
 		});
+		Ok(())
 	}
-	// add:
-	cups_options.add(&option);
-	Ok(())
 }
 
 /// Maps an instance of [`cups::cups_dest_t`] to a [`Printer`].
@@ -1,12 +1,14 @@
 use std::borrow::Cow;
 use std::ffi::{CStr, CString};
-use std::ptr;
+use std::ops::RangeInclusive;
+use std::{ptr, slice};
 
+use crate::error::PrintError;
 use crate::options::*;
 use crate::print::unix::cups;
 use crate::print::unix::cups::consts::opts;
 use crate::print::unix::dest::{CupsDestination, CupsDestinationInfo};
-use crate::print::util::FatPointerMut;
+use crate::print::util::{self, FatPointerMut};
 
 // MARK: - Cups Options Struct
 
@@ -27,27 +29,99 @@ impl CupsOptions {
 			},
 		}
 	}
+	/// Wraps an array of options allocated by CUPS (for example, returned by a CUPS function).
+	/// The array is freed when the returned value is dropped.
+	///
+	/// # Safety
+	/// `opts` must either be null, or point to an array of `opts.size` options allocated by CUPS,
+	/// which is not freed anywhere else.
+	pub unsafe fn from_fat_ptr(opts: FatPointerMut<cups::cups_option_t>) -> Self {
+		Self { opts }
+	}
 	/// Adds a CUPS option to this instance.
 	pub fn add<O>(&mut self, option: &O)
 	where
 		O: CupsOption,
 	{
+		self.add_raw(O::get_cups_option_name(), &option.get_cups_option_value());
+	}
+	/// Adds an option with the specified name and value to this instance.
+	/// If an option with the same name is already present, its value is replaced.
+	pub fn add_raw(&mut self, name: &CStr, value: &CStr) {
 		// SAFETY: `cupsAddOption` accepts a name, value, current number of elements, and a pointer to
 		// `*mut cups_option_t`. It returns the new number of elements and writes a valid pointer
 		// into `self.opts.ptr`, after allocating an array of options. Thus repeated calls are safe
 		// until the memory is freed by calling `cupsFreeOptions`.
 		unsafe {
 			self.opts.size = cups::cupsAddOption(
-				O::get_cups_option_name().as_ptr(),
-				option.get_cups_option_value().as_ptr(),
+				name.as_ptr(),
+				value.as_ptr(),
 				self.opts.size,
 				&mut self.opts.ptr,
 			);
 		};
 	}
+	/// Parses options from a string in the syntax of `lp -o`, such as `media=A4 sides=one-sided`,
+	/// using `cupsParseOptions`.
+	pub fn parse(options: &CStr) -> Self {
+		let mut parsed = Self::new();
+		// SAFETY: `options` is a valid C string. `cupsParseOptions` accepts the current number of
+		// elements and a pointer to `*mut cups_option_t`, and returns the new number of elements
+		// after writing a valid pointer into `parsed.opts.ptr`.
+		unsafe {
+			parsed.opts.size =
+				cups::cupsParseOptions(options.as_ptr(), parsed.opts.size, &mut parsed.opts.ptr);
+		}
+		parsed
+	}
+	/// Removes the option with the specified name from this instance, if present.
+	pub fn remove(&mut self, name: &CStr) {
+		if self.opts.ptr.is_null() {
+			return;
+		}
+		// SAFETY: `self.opts` is a valid, non-null fat pointer to options allocated by CUPS, and
+		// `name` is a valid C string. `cupsRemoveOption` returns the new number of elements.
+		unsafe {
+			self.opts.size =
+				cups::cupsRemoveOption(name.as_ptr(), self.opts.size, &mut self.opts.ptr);
+		}
+	}
+	/// Returns the value of the option with the specified name, or `None` if it is not present.
+	pub fn get(&self, name: &str) -> Option<String> {
+		self.to_pairs()
+			.into_iter()
+			.find(|(key, _)| key == name)
+			.map(|(_, value)| value)
+	}
+	/// Returns the names and values of the options in this instance.
+	pub fn to_pairs(&self) -> Vec<(String, String)> {
+		if self.opts.is_null() {
+			return Vec::new();
+		}
+		// SAFETY: `self.opts` is a valid, non-null fat pointer to options allocated by CUPS, and each
+		// option contains valid pointers to C strings.
+		unsafe {
+			slice::from_raw_parts(self.opts.ptr, self.opts.size as usize)
+				.iter()
+				.map(|opt| {
+					(
+						util::cstr_to_string(opt.name),
+						util::cstr_to_string(opt.value),
+					)
+				})
+				.collect()
+		}
+	}
+	/// Returns a copy of this list of options.
+	pub fn try_clone(&self) -> Result<Self, PrintError> {
+		let mut clone = Self::new();
+		for (name, value) in self.to_pairs() {
+			clone.add_raw(&CString::new(name)?, &CString::new(value)?);
+		}
+		Ok(clone)
+	}
 	/// Checks with a particular destination whether the option and its value are supported.
 	pub fn validate<O>(
-		&self,
 		destination: &mut CupsDestination,
 		info: &mut CupsDestinationInfo,
 		option: &O,
@@ -55,6 +129,22 @@ impl CupsOptions {
 	where
 		O: CupsOption,
 	{
+		if !O::is_advertised() {
+			return true;
+		}
+		option
+			.get_cups_supported_pairs()
+			.iter()
+			.all(|(name, value)| Self::validate_raw(destination, info, name, value))
+	}
+	/// Checks with a particular destination whether the option with the specified name and value
+	/// is supported.
+	pub fn validate_raw(
+		destination: &mut CupsDestination,
+		info: &mut CupsDestinationInfo,
+		name: &CStr,
+		value: &CStr,
+	) -> bool {
 		// SAFETY: `destination` is a CupsDestination instance, which can only be constructed safely,
 		// contains a reference and thus the pointer it dereferences to is valid.
 		let result = unsafe {
@@ -62,8 +152,8 @@ impl CupsOptions {
 				cups::consts::http::CUPS_HTTP_DEFAULT,
 				destination.as_mut_ptr(),
 				info.as_mut_ptr(),
-				O::get_cups_option_name().as_ptr(),
-				option.get_cups_option_value().as_ptr(),
+				name.as_ptr(),
+				value.as_ptr(),
 			)
 		};
 		result == cups::consts::bool(true)
@@ -89,16 +179,290 @@ impl Drop for CupsOptions {
 	}
 }
 
+// MARK: - Visiting options
+
+/// Checks whether the value of `option` is valid, regardless of the printer.
+/// Returns [`PrintError::InvalidOption`] if it is not.
+pub fn check_option_value<O>(option: &O) -> Result<(), PrintError>
+where
+	O: CupsOption,
+{
+	option
+		.check_value()
+		.map_err(|reason| PrintError::InvalidOption {
+			name: O::get_name().to_lowercase(),
+			value: option.to_human_string(),
+			reason: reason.to_owned(),
+		})
+}
+
+/// A trait for types that process the options set in a [`PrintOptions`] instance, one at a time.
+pub trait CupsOptionVisitor {
+	/// Processes a single option.
+	fn visit<O: CupsOption>(&mut self, option: &O) -> Result<(), PrintError>;
+}
+impl CupsOptionVisitor for CupsOptions {
+	/// Adds the option to this instance, without validation.
+	fn visit<O: CupsOption>(&mut self, option: &O) -> Result<(), PrintError> {
+		self.add(option);
+		Ok(())
+	}
+}
+
+/// Calls `visitor` for each option that is set in `options`.
+/// Stops at, and returns, the first error returned by `visitor`.
+pub fn visit_options<V>(options: &PrintOptions, visitor: &mut V) -> Result<(), PrintError>
+where
+	V: CupsOptionVisitor,
+{
+	visit_option(&options.copies, visitor)?;
+	visit_option(&options.collate, visitor)?;
+	visit_option(&options.finishings, visitor)?;
+	visit_option(&options.finishing_templates, visitor)?;
+	visit_option(&options.media_size, visitor)?;
+	visit_option(&options.media_source, visitor)?;
+	visit_option(&options.media_type, visitor)?;
+	visit_option(&options.number_up, visitor)?;
+	visit_option(&options.number_up_layout, visitor)?;
+	visit_option(&options.page_border, visitor)?;
+	visit_option(&options.orientation, visitor)?;
+	visit_option(&options.page_ranges, visitor)?;
+	visit_option(&options.print_scaling, visitor)?;
+	visit_option(&options.fit_to_page, visitor)?;
+	visit_option(&options.mirror, visitor)?;
+	visit_option(&options.color_mode, visitor)?;
+	visit_option(&options.quality, visitor)?;
+	visit_option(&options.printer_resolution, visitor)?;
+	visit_option(&options.print_rendering_intent, visitor)?;
+	visit_option(&options.sides_mode, visitor)?;
+	visit_option(&options.output_order, visitor)?;
+	visit_option(&options.output_bin, visitor)?;
+	visit_option(&options.retention, visitor)?;
+	visit_option(&options.job_priority, visitor)?;
+	visit_option(&options.job_sheets, visitor)?;
+	visit_option(&options.job_account_id, visitor)?;
+	visit_option(&options.job_accounting_user_id, visitor)?;
+	visit_option(&options.compression, visitor)?;
+	visit_option(&options.job_password, visitor)?;
+	Ok(())
+}
+/// Calls `visitor` for each option that is set in `options`.
+/// Stops at, and returns, the first error returned by `visitor`.
+pub fn visit_document_options<V>(
+	options: &DocumentOptions,
+	visitor: &mut V,
+) -> Result<(), PrintError>
+where
+	V: CupsOptionVisitor,
+{
+	visit_option(&options.copies, visitor)?;
+	visit_option(&options.media_size, visitor)?;
+	visit_option(&options.media_type, visitor)?;
+	visit_option(&options.page_ranges, visitor)?;
+	visit_option(&options.color_mode, visitor)?;
+	visit_option(&options.sides_mode, visitor)?;
+	Ok(())
+}
+fn visit_option<O, V>(option: &Option<O>, visitor: &mut V) -> Result<(), PrintError>
+where
+	O: CupsOption,
+	V: CupsOptionVisitor,
+{
+	match option {
+		Some(option) => visitor.visit(option),
+		None => Ok(()),
+	}
+}
+
+// MARK: - Option strings
+
+/// Parses options from a string in the syntax of `lp -o`; see [`PrintOptions::from_cups_options()`].
+pub fn parse_cups_options(options: &str) -> Result<PrintOptions, PrintError> {
+	let cups_options = CupsOptions::parse(&CString::new(options)?);
+	let mut print_options = PrintOptions::default();
+	for (name, value) in cups_options.to_pairs() {
+		set_cups_option(&mut print_options, &name, &value);
+	}
+	Ok(print_options)
+}
+/// Formats options as a string in the syntax of `lp -o`; see [`PrintOptions::to_cups_options()`].
+pub fn format_cups_options(options: &PrintOptions) -> String {
+	let mut cups_options = CupsOptions::new();
+	visit_options(options, &mut cups_options)
+		.expect("Adding options to CupsOptions without validation can't fail");
+	let mut pairs = cups_options.to_pairs();
+	pairs.extend(options.extra.iter().map(|(k, v)| (k.clone(), v.clone())));
+	pairs
+		.iter()
+		// The job password is dropped (whether set as a field or as an extra option), so that it
+		// is not exposed in the string:
+		.filter(|(name, _)| name.as_bytes() != opts::CUPS_JOB_PASSWORD.to_bytes())
+		.map(|(name, value)| format!("{name}={}", quote_option_value(value)))
+		.collect::<Vec<_>>()
+		.join(" ")
+}
+
+/// Sets the field of `options` that corresponds to the CUPS option `name` to `value`.
+///
+/// If there is no such field, or if `value` can't be converted to its type, the option is kept in
+/// [`PrintOptions::extra`] instead, and the field (if any) is left unchanged.
+pub fn set_cups_option(options: &mut PrintOptions, name: &str, value: &str) {
+	set_or_keep_extra(options, name, value, Unconvertible::KeepField);
+}
+/// Sets the field of `options` that corresponds to the CUPS option `name` to `value`, replacing
+/// the previous value of the option.
+///
+/// If there is no such field, or if `value` can't be converted to its type, the option is kept in
+/// [`PrintOptions::extra`] instead, and the field (if any) is cleared.
+pub fn replace_cups_option(options: &mut PrintOptions, name: &str, value: &str) {
+	set_or_keep_extra(options, name, value, Unconvertible::ClearField);
+}
+fn set_or_keep_extra(
+	options: &mut PrintOptions,
+	name: &str,
+	value: &str,
+	unconvertible: Unconvertible,
+) {
+	if set_option(options, name, value, unconvertible) {
+		options.extra.remove(name);
+	} else {
+		options.extra.insert(name.to_owned(), value.to_owned());
+	}
+}
+
+/// Determines what happens to a field if a value can't be converted to its type.
+#[derive(Clone, Copy)]
+enum Unconvertible {
+	KeepField,
+	ClearField,
+}
+
+/// Sets the field of `options` that corresponds to the CUPS option `name` to `value`.
+/// Returns `false` if there is no such field, or if `value` can't be converted to its type (in
+/// which case the field is handled according to `unconvertible`).
+fn set_option(
+	options: &mut PrintOptions,
+	name: &str,
+	value: &str,
+	unconvertible: Unconvertible,
+) -> bool {
+	set_collate(options, name, value)
+		|| set_field(&mut options.copies, name, value, unconvertible)
+		|| set_field(&mut options.collate, name, value, unconvertible)
+		|| set_field(&mut options.finishings, name, value, unconvertible)
+		|| set_field(&mut options.finishing_templates, name, value, unconvertible)
+		|| set_field(&mut options.media_size, name, value, unconvertible)
+		|| set_field(&mut options.media_source, name, value, unconvertible)
+		|| set_field(&mut options.media_type, name, value, unconvertible)
+		|| set_field(&mut options.number_up, name, value, unconvertible)
+		|| set_field(&mut options.number_up_layout, name, value, unconvertible)
+		|| set_field(&mut options.page_border, name, value, unconvertible)
+		|| set_field(&mut options.orientation, name, value, unconvertible)
+		|| set_field(&mut options.page_ranges, name, value, unconvertible)
+		|| set_field(&mut options.print_scaling, name, value, unconvertible)
+		|| set_field(&mut options.fit_to_page, name, value, unconvertible)
+		|| set_field(&mut options.mirror, name, value, unconvertible)
+		|| set_field(&mut options.color_mode, name, value, unconvertible)
+		|| set_field(&mut options.quality, name, value, unconvertible)
+		|| set_field(&mut options.printer_resolution, name, value, unconvertible)
+		|| set_field(
+			&mut options.print_rendering_intent,
+			name,
+			value,
+			unconvertible,
+		) || set_field(&mut options.sides_mode, name, value, unconvertible)
+		|| set_field(&mut options.output_order, name, value, unconvertible)
+		|| set_field(&mut options.output_bin, name, value, unconvertible)
+		|| set_field(&mut options.retention, name, value, unconvertible)
+		|| set_field(&mut options.job_priority, name, value, unconvertible)
+		|| set_field(&mut options.job_sheets, name, value, unconvertible)
+		|| set_field(&mut options.job_account_id, name, value, unconvertible)
+		|| set_field(
+			&mut options.job_accounting_user_id,
+			name,
+			value,
+			unconvertible,
+		) || set_field(&mut options.compression, name, value, unconvertible)
+		|| set_field(&mut options.job_password, name, value, unconvertible)
+}
+/// Sets [`PrintOptions::collate`] from the classic `collate` option of `lp`, such as
+/// `collate=false`. Returns `false` if `name` is not `collate`, or if `value` is not a boolean.
+fn set_collate(options: &mut PrintOptions, name: &str, value: &str) -> bool {
+	if name.as_bytes() != opts::CUPS_COLLATE.to_bytes() {
+		return false;
+	}
+	let collation = match parse_bool(value) {
+		Some(true) => Collation::Collated,
+		Some(false) => Collation::Uncollated,
+		None => return false,
+	};
+	options.collate = Some(collation);
+	true
+}
+
+/// Sets `field` to `value`, if `name` is the CUPS option name of its type.
+/// Returns `false` if it is not, or if `value` can't be converted to the type; in that case, the
+/// field is only cleared if `unconvertible` is [`Unconvertible::ClearField`].
+fn set_field<O>(
+	field: &mut Option<O>,
+	name: &str,
+	value: &str,
+	unconvertible: Unconvertible,
+) -> bool
+where
+	O: CupsOption,
+{
+	if name.as_bytes() != O::get_cups_option_name().to_bytes() {
+		return false;
+	}
+	match (O::from_ipp(value), unconvertible) {
+		(Some(option), _) => {
+			*field = Some(option);
+			true
+		}
+		(None, Unconvertible::KeepField) => false,
+		(None, Unconvertible::ClearField) => {
+			*field = None;
+			false
+		}
+	}
+}
+
+/// Quotes an option value for `cupsParseOptions`, if it contains whitespace or quotes.
+/// Collections (such as `{media-size={...}}`) are left unchanged.
+fn quote_option_value(value: &str) -> String {
+	let needs_quotes = value.is_empty()
+		|| value
+			.chars()
+			.any(|c| c.is_whitespace() || matches!(c, '"' | '\'' | '\\'));
+	if !needs_quotes || value.starts_with('{') {
+		return value.to_owned();
+	}
+	let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+	format!("\"{escaped}\"")
+}
+
 // MARK: - CupsOption trait
 
 /// A trait that designates an option that can be converted to a CUPS option value string.
-pub trait CupsOption: PrintOption {
+pub trait CupsOption: PrintOption + FromIpp {
 	/// Converts the option's type to a name accepted by CUPS.
 	/// Returns a borrowed C string.
 	fn get_cups_option_name() -> &'static CStr;
 	/// Converts the option's value to a string accepted by CUPS.
 	/// Returns either a borrowed or an owned value inside a [`Cow`] pointer.
 	fn get_cups_option_value(&self) -> Cow<'static, CStr>;
+	/// Returns the names and values used to check whether the printer supports this option; all
+	/// of them must be supported. By default, these are the option's name and value.
+	fn get_cups_supported_pairs(&self) -> Vec<(&'static CStr, Cow<'static, CStr>)> {
+		vec![(Self::get_cups_option_name(), self.get_cups_option_value())]
+	}
+	/// Returns `true` if printers advertise their support of this option (with a `*-supported`
+	/// attribute), and `false` if the option is processed by CUPS filters instead. Options that
+	/// are not advertised can't be validated, and are always considered supported.
+	fn is_advertised() -> bool {
+		true
+	}
 }
 
 impl CupsOption for CopiesInt {
@@ -114,18 +478,93 @@ impl CupsOption for CopiesInt {
 	}
 }
 
+impl CupsOption for Collation {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_MULTIPLE_DOCUMENT_HANDLING
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		Cow::Borrowed(match self {
+			Collation::Collated => opts::values::CUPS_MULTIPLE_DOCUMENT_HANDLING_COLLATED,
+			Collation::Uncollated => opts::values::CUPS_MULTIPLE_DOCUMENT_HANDLING_UNCOLLATED,
+		})
+	}
+}
+
 impl CupsOption for Finishing {
 	fn get_cups_option_name() -> &'static CStr {
 		opts::CUPS_FINISHINGS
 	}
 	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
 		Cow::Borrowed(match self {
-			Finishing::Bind => opts::values::CUPS_FINISHINGS_BIND,
+			Finishing::Staple => opts::values::CUPS_FINISHINGS_STAPLE,
+			Finishing::Punch => opts::values::CUPS_FINISHINGS_PUNCH,
 			Finishing::Cover => opts::values::CUPS_FINISHINGS_COVER,
+			Finishing::Bind => opts::values::CUPS_FINISHINGS_BIND,
+			Finishing::SaddleStitch => opts::values::CUPS_FINISHINGS_SADDLE_STITCH,
+			Finishing::EdgeStitch => opts::values::CUPS_FINISHINGS_EDGE_STITCH,
 			Finishing::Fold => opts::values::CUPS_FINISHINGS_FOLD,
-			Finishing::Punch => opts::values::CUPS_FINISHINGS_PUNCH,
-			Finishing::Staple => opts::values::CUPS_FINISHINGS_STAPLE,
 			Finishing::Trim => opts::values::CUPS_FINISHINGS_TRIM,
+			Finishing::Bale => opts::values::CUPS_FINISHINGS_BALE,
+			Finishing::BookletMaker => opts::values::CUPS_FINISHINGS_BOOKLET_MAKER,
+			Finishing::JogOffset => opts::values::CUPS_FINISHINGS_JOG_OFFSET,
+			Finishing::Coat => opts::values::CUPS_FINISHINGS_COAT,
+			Finishing::Laminate => opts::values::CUPS_FINISHINGS_LAMINATE,
+			Finishing::StapleTopLeft => opts::values::CUPS_FINISHINGS_STAPLE_TOP_LEFT,
+			Finishing::StapleBottomLeft => opts::values::CUPS_FINISHINGS_STAPLE_BOTTOM_LEFT,
+			Finishing::StapleTopRight => opts::values::CUPS_FINISHINGS_STAPLE_TOP_RIGHT,
+			Finishing::StapleBottomRight => opts::values::CUPS_FINISHINGS_STAPLE_BOTTOM_RIGHT,
+			Finishing::EdgeStitchLeft => opts::values::CUPS_FINISHINGS_EDGE_STITCH_LEFT,
+			Finishing::EdgeStitchTop => opts::values::CUPS_FINISHINGS_EDGE_STITCH_TOP,
+			Finishing::EdgeStitchRight => opts::values::CUPS_FINISHINGS_EDGE_STITCH_RIGHT,
+			Finishing::EdgeStitchBottom => opts::values::CUPS_FINISHINGS_EDGE_STITCH_BOTTOM,
+			Finishing::StapleDualLeft => opts::values::CUPS_FINISHINGS_STAPLE_DUAL_LEFT,
+			Finishing::StapleDualTop => opts::values::CUPS_FINISHINGS_STAPLE_DUAL_TOP,
+			Finishing::StapleDualRight => opts::values::CUPS_FINISHINGS_STAPLE_DUAL_RIGHT,
+			Finishing::StapleDualBottom => opts::values::CUPS_FINISHINGS_STAPLE_DUAL_BOTTOM,
+			Finishing::StapleTripleLeft => opts::values::CUPS_FINISHINGS_STAPLE_TRIPLE_LEFT,
+			Finishing::StapleTripleTop => opts::values::CUPS_FINISHINGS_STAPLE_TRIPLE_TOP,
+			Finishing::StapleTripleRight => opts::values::CUPS_FINISHINGS_STAPLE_TRIPLE_RIGHT,
+			Finishing::StapleTripleBottom => opts::values::CUPS_FINISHINGS_STAPLE_TRIPLE_BOTTOM,
+			Finishing::BindLeft => opts::values::CUPS_FINISHINGS_BIND_LEFT,
+			Finishing::BindTop => opts::values::CUPS_FINISHINGS_BIND_TOP,
+			Finishing::BindRight => opts::values::CUPS_FINISHINGS_BIND_RIGHT,
+			Finishing::BindBottom => opts::values::CUPS_FINISHINGS_BIND_BOTTOM,
+			Finishing::TrimAfterPages => opts::values::CUPS_FINISHINGS_TRIM_AFTER_PAGES,
+			Finishing::TrimAfterDocuments => opts::values::CUPS_FINISHINGS_TRIM_AFTER_DOCUMENTS,
+			Finishing::TrimAfterCopies => opts::values::CUPS_FINISHINGS_TRIM_AFTER_COPIES,
+			Finishing::TrimAfterJob => opts::values::CUPS_FINISHINGS_TRIM_AFTER_JOB,
+			Finishing::PunchTopLeft => opts::values::CUPS_FINISHINGS_PUNCH_TOP_LEFT,
+			Finishing::PunchBottomLeft => opts::values::CUPS_FINISHINGS_PUNCH_BOTTOM_LEFT,
+			Finishing::PunchTopRight => opts::values::CUPS_FINISHINGS_PUNCH_TOP_RIGHT,
+			Finishing::PunchBottomRight => opts::values::CUPS_FINISHINGS_PUNCH_BOTTOM_RIGHT,
+			Finishing::PunchDualLeft => opts::values::CUPS_FINISHINGS_PUNCH_DUAL_LEFT,
+			Finishing::PunchDualTop => opts::values::CUPS_FINISHINGS_PUNCH_DUAL_TOP,
+			Finishing::PunchDualRight => opts::values::CUPS_FINISHINGS_PUNCH_DUAL_RIGHT,
+			Finishing::PunchDualBottom => opts::values::CUPS_FINISHINGS_PUNCH_DUAL_BOTTOM,
+			Finishing::PunchTripleLeft => opts::values::CUPS_FINISHINGS_PUNCH_TRIPLE_LEFT,
+			Finishing::PunchTripleTop => opts::values::CUPS_FINISHINGS_PUNCH_TRIPLE_TOP,
+			Finishing::PunchTripleRight => opts::values::CUPS_FINISHINGS_PUNCH_TRIPLE_RIGHT,
+			Finishing::PunchTripleBottom => opts::values::CUPS_FINISHINGS_PUNCH_TRIPLE_BOTTOM,
+			Finishing::PunchQuadLeft => opts::values::CUPS_FINISHINGS_PUNCH_QUAD_LEFT,
+			Finishing::PunchQuadTop => opts::values::CUPS_FINISHINGS_PUNCH_QUAD_TOP,
+			Finishing::PunchQuadRight => opts::values::CUPS_FINISHINGS_PUNCH_QUAD_RIGHT,
+			Finishing::PunchQuadBottom => opts::values::CUPS_FINISHINGS_PUNCH_QUAD_BOTTOM,
+			Finishing::PunchMultipleLeft => opts::values::CUPS_FINISHINGS_PUNCH_MULTIPLE_LEFT,
+			Finishing::PunchMultipleTop => opts::values::CUPS_FINISHINGS_PUNCH_MULTIPLE_TOP,
+			Finishing::PunchMultipleRight => opts::values::CUPS_FINISHINGS_PUNCH_MULTIPLE_RIGHT,
+			Finishing::PunchMultipleBottom => opts::values::CUPS_FINISHINGS_PUNCH_MULTIPLE_BOTTOM,
+			Finishing::FoldAccordion => opts::values::CUPS_FINISHINGS_FOLD_ACCORDION,
+			Finishing::FoldDoubleGate => opts::values::CUPS_FINISHINGS_FOLD_DOUBLE_GATE,
+			Finishing::FoldGate => opts::values::CUPS_FINISHINGS_FOLD_GATE,
+			Finishing::FoldHalf => opts::values::CUPS_FINISHINGS_FOLD_HALF,
+			Finishing::FoldHalfZ => opts::values::CUPS_FINISHINGS_FOLD_HALF_Z,
+			Finishing::FoldLeftGate => opts::values::CUPS_FINISHINGS_FOLD_LEFT_GATE,
+			Finishing::FoldLetter => opts::values::CUPS_FINISHINGS_FOLD_LETTER,
+			Finishing::FoldParallel => opts::values::CUPS_FINISHINGS_FOLD_PARALLEL,
+			Finishing::FoldPoster => opts::values::CUPS_FINISHINGS_FOLD_POSTER,
+			Finishing::FoldRightGate => opts::values::CUPS_FINISHINGS_FOLD_RIGHT_GATE,
+			Finishing::FoldZ => opts::values::CUPS_FINISHINGS_FOLD_Z,
+			Finishing::FoldEngineeringZ => opts::values::CUPS_FINISHINGS_FOLD_ENGINEERING_Z,
 		})
 	}
 }
@@ -153,6 +592,55 @@ impl CupsOption for Vec<Finishing> {
 	}
 }
 
+impl CupsOption for Vec<FinishingTemplate> {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_FINISHINGS_COL
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		// We want a comma-separated list of collections here, such as
+		// `{finishing-template=staple-top-left},{finishing-template=punch-dual-left}`:
+		let string = self
+			.iter()
+			.map(|template| {
+				let template = quote_collection_value(&template.0);
+				format!("{{finishing-template={template}}}")
+			})
+			.collect::<Vec<_>>()
+			.join(",")
+			.replace('\0', "");
+		// SAFETY: 0 bytes have been removed from `string` above.
+		let c_string =
+			CString::new(string).expect("Could not convert finishing templates to CString");
+		Cow::Owned(c_string)
+	}
+	fn get_cups_supported_pairs(&self) -> Vec<(&'static CStr, Cow<'static, CStr>)> {
+		// Printers advertise supported templates in `finishing-template-supported`, which is
+		// checked for each template:
+		self.iter()
+			.map(|template| {
+				// SAFETY: 0 bytes are removed from the string.
+				let c_string = CString::new(template.0.replace('\0', ""))
+					.expect("Could not convert finishing template to CString");
+				(opts::CUPS_FINISHING_TEMPLATE, Cow::Owned(c_string))
+			})
+			.collect()
+	}
+}
+
+/// Quotes a value within a collection (such as `{finishing-template=...}`), if it contains
+/// characters that would end the value early, such as whitespace, commas, or braces.
+fn quote_collection_value(value: &str) -> String {
+	let needs_quotes = value.is_empty()
+		|| value
+			.chars()
+			.any(|c| c.is_whitespace() || matches!(c, '"' | '\'' | '\\' | ',' | '=' | '{' | '}'));
+	if !needs_quotes {
+		return value.to_owned();
+	}
+	let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+	format!("\"{escaped}\"")
+}
+
 impl CupsOption for MediaSize {
 	fn get_cups_option_name() -> &'static CStr {
 		opts::CUPS_MEDIA
@@ -186,10 +674,11 @@ impl CupsOption for MediaSource {
 		opts::CUPS_MEDIA_SOURCE
 	}
 	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
-		Cow::Borrowed(match self {
-			MediaSource::Auto => opts::values::CUPS_MEDIA_SOURCE_AUTO,
-			MediaSource::Manual => opts::values::CUPS_MEDIA_SOURCE_MANUAL,
-		})
+		// The CUPS value is the `media-source` keyword:
+		let string = self.to_string().replace('\0', "");
+		// SAFETY: 0 bytes have been removed from `string` above.
+		let c_string = CString::new(string).expect("Could not convert media source to CString");
+		Cow::Owned(c_string)
 	}
 }
 
@@ -225,6 +714,42 @@ impl CupsOption for NumberUpInt {
 	}
 }
 
+impl CupsOption for NumberUpLayout {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_NUMBER_UP_LAYOUT
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		Cow::Borrowed(match self {
+			NumberUpLayout::Lrtb => opts::values::CUPS_NUMBER_UP_LAYOUT_LRTB,
+			NumberUpLayout::Lrbt => opts::values::CUPS_NUMBER_UP_LAYOUT_LRBT,
+			NumberUpLayout::Rltb => opts::values::CUPS_NUMBER_UP_LAYOUT_RLTB,
+			NumberUpLayout::Rlbt => opts::values::CUPS_NUMBER_UP_LAYOUT_RLBT,
+			NumberUpLayout::Tblr => opts::values::CUPS_NUMBER_UP_LAYOUT_TBLR,
+			NumberUpLayout::Tbrl => opts::values::CUPS_NUMBER_UP_LAYOUT_TBRL,
+			NumberUpLayout::Btlr => opts::values::CUPS_NUMBER_UP_LAYOUT_BTLR,
+			NumberUpLayout::Btrl => opts::values::CUPS_NUMBER_UP_LAYOUT_BTRL,
+		})
+	}
+}
+
+impl CupsOption for PageBorder {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_PAGE_BORDER
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		Cow::Borrowed(match self {
+			PageBorder::None => opts::values::CUPS_PAGE_BORDER_NONE,
+			PageBorder::Single => opts::values::CUPS_PAGE_BORDER_SINGLE,
+			PageBorder::SingleThick => opts::values::CUPS_PAGE_BORDER_SINGLE_THICK,
+			PageBorder::Double => opts::values::CUPS_PAGE_BORDER_DOUBLE,
+			PageBorder::DoubleThick => opts::values::CUPS_PAGE_BORDER_DOUBLE_THICK,
+		})
+	}
+	fn is_advertised() -> bool {
+		false
+	}
+}
+
 impl CupsOption for Orientation {
 	fn get_cups_option_name() -> &'static CStr {
 		opts::CUPS_ORIENTATION
@@ -233,8 +758,74 @@ impl CupsOption for Orientation {
 		Cow::Borrowed(match self {
 			Orientation::Portrait => opts::values::CUPS_ORIENTATION_PORTRAIT,
 			Orientation::Landscape => opts::values::CUPS_ORIENTATION_LANDSCAPE,
+			Orientation::ReverseLandscape => opts::values::CUPS_ORIENTATION_REVERSE_LANDSCAPE,
+			Orientation::ReversePortrait => opts::values::CUPS_ORIENTATION_REVERSE_PORTRAIT,
+			Orientation::None => opts::values::CUPS_ORIENTATION_NONE,
+		})
+	}
+}
+
+impl CupsOption for Vec<RangeInclusive<u32>> {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_PAGE_RANGES
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		// We want a comma-separated string of ranges here, such as `1-3,5-5`:
+		let string = self
+			.iter()
+			.map(|range| format!("{}-{}", range.start(), range.end()))
+			.collect::<Vec<_>>()
+			.join(",");
+		// SAFETY: `string` is built from integers, and the '-' and ',' characters, and thus does not
+		// contain 0 bytes.
+		let c_string = CString::new(string).expect("Could not convert page ranges to CString");
+		Cow::Owned(c_string)
+	}
+}
+
+impl CupsOption for PrintScaling {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_PRINT_SCALING
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		Cow::Borrowed(match self {
+			PrintScaling::Auto => opts::values::CUPS_PRINT_SCALING_AUTO,
+			PrintScaling::AutoFit => opts::values::CUPS_PRINT_SCALING_AUTO_FIT,
+			PrintScaling::Fill => opts::values::CUPS_PRINT_SCALING_FILL,
+			PrintScaling::Fit => opts::values::CUPS_PRINT_SCALING_FIT,
+			PrintScaling::None => opts::values::CUPS_PRINT_SCALING_NONE,
+		})
+	}
+}
+
+impl CupsOption for FitToPageBool {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_FIT_TO_PAGE
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		Cow::Borrowed(match self.0 {
+			true => opts::values::CUPS_TRUE,
+			false => opts::values::CUPS_FALSE,
+		})
+	}
+	fn is_advertised() -> bool {
+		false
+	}
+}
+
+impl CupsOption for MirrorBool {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_MIRROR
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		Cow::Borrowed(match self.0 {
+			true => opts::values::CUPS_TRUE,
+			false => opts::values::CUPS_FALSE,
 		})
 	}
+	fn is_advertised() -> bool {
+		false
+	}
 }
 
 impl CupsOption for ColorMode {
@@ -246,6 +837,10 @@ impl CupsOption for ColorMode {
 			ColorMode::Auto => opts::values::CUPS_PRINT_COLOR_MODE_AUTO,
 			ColorMode::Monochrome => opts::values::CUPS_PRINT_COLOR_MODE_MONOCHROME,
 			ColorMode::Color => opts::values::CUPS_PRINT_COLOR_MODE_COLOR,
+			ColorMode::BiLevel => opts::values::CUPS_PRINT_COLOR_MODE_BI_LEVEL,
+			ColorMode::ProcessMonochrome => opts::values::CUPS_PRINT_COLOR_MODE_PROCESS_MONOCHROME,
+			ColorMode::Highlight => opts::values::CUPS_PRINT_COLOR_MODE_HIGHLIGHT,
+			ColorMode::AutoMonochrome => opts::values::CUPS_PRINT_COLOR_MODE_AUTO_MONOCHROME,
 		})
 	}
 }
@@ -263,6 +858,35 @@ impl CupsOption for Quality {
 	}
 }
 
+impl CupsOption for Resolution {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_PRINTER_RESOLUTION
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		// SAFETY: the string is built from integers and the units, such as `600x600dpi`, and thus
+		// does not contain 0 bytes.
+		let c_string =
+			CString::new(self.to_string()).expect("Could not convert resolution to CString");
+		Cow::Owned(c_string)
+	}
+}
+
+impl CupsOption for RenderingIntent {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_PRINT_RENDERING_INTENT
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		Cow::Borrowed(match self {
+			RenderingIntent::Auto => opts::values::CUPS_PRINT_RENDERING_INTENT_AUTO,
+			RenderingIntent::Perceptual => opts::values::CUPS_PRINT_RENDERING_INTENT_PERCEPTUAL,
+			RenderingIntent::Relative => opts::values::CUPS_PRINT_RENDERING_INTENT_RELATIVE,
+			RenderingIntent::RelativeBpc => opts::values::CUPS_PRINT_RENDERING_INTENT_RELATIVE_BPC,
+			RenderingIntent::Saturation => opts::values::CUPS_PRINT_RENDERING_INTENT_SATURATION,
+			RenderingIntent::Absolute => opts::values::CUPS_PRINT_RENDERING_INTENT_ABSOLUTE,
+		})
+	}
+}
+
 impl CupsOption for SidesMode {
 	fn get_cups_option_name() -> &'static CStr {
 		opts::CUPS_SIDES
@@ -276,14 +900,166 @@ impl CupsOption for SidesMode {
 	}
 }
 
+impl CupsOption for OutputOrder {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_OUTPUT_ORDER
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		Cow::Borrowed(match self {
+			OutputOrder::Normal => opts::values::CUPS_OUTPUT_ORDER_NORMAL,
+			OutputOrder::Reverse => opts::values::CUPS_OUTPUT_ORDER_REVERSE,
+		})
+	}
+	fn is_advertised() -> bool {
+		false
+	}
+}
+
+impl CupsOption for OutputBin {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_OUTPUT_BIN
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		// The CUPS value is the `output-bin` keyword:
+		let string = self.to_string().replace('\0', "");
+		// SAFETY: 0 bytes have been removed from `string` above.
+		let c_string = CString::new(string).expect("Could not convert output bin to CString");
+		Cow::Owned(c_string)
+	}
+}
+
+impl CupsOption for JobRetention {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_JOB_RETAIN_UNTIL
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		Cow::Borrowed(match self {
+			JobRetention::Indefinite => opts::values::CUPS_JOB_RETAIN_UNTIL_INDEFINITE,
+			JobRetention::DayTime => opts::values::CUPS_JOB_RETAIN_UNTIL_DAY_TIME,
+			JobRetention::Evening => opts::values::CUPS_JOB_RETAIN_UNTIL_EVENING,
+			JobRetention::Night => opts::values::CUPS_JOB_RETAIN_UNTIL_NIGHT,
+			JobRetention::Weekend => opts::values::CUPS_JOB_RETAIN_UNTIL_WEEKEND,
+			JobRetention::SecondShift => opts::values::CUPS_JOB_RETAIN_UNTIL_SECOND_SHIFT,
+			JobRetention::ThirdShift => opts::values::CUPS_JOB_RETAIN_UNTIL_THIRD_SHIFT,
+		})
+	}
+}
+
+impl CupsOption for JobPriorityInt {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_JOB_PRIORITY
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		let string = self.0.to_string();
+		// SAFETY: `string` is built from a C integer, and thus contains only bytes which correspond
+		// to digit characters, and no 0 bytes.
+		let c_string = CString::new(string).expect("Could not convert job priority to CString");
+		Cow::Owned(c_string)
+	}
+	fn is_advertised() -> bool {
+		// `job-priority-supported` is the number of priority levels, not a list of values, and thus
+		// can't be used for validation.
+		false
+	}
+}
+
+impl CupsOption for JobSheets {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_JOB_SHEETS
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		// SAFETY: the string is built from keywords, such as `standard,none`, and thus does not
+		// contain 0 bytes.
+		let c_string =
+			CString::new(self.to_string()).expect("Could not convert job sheets to CString");
+		Cow::Owned(c_string)
+	}
+	fn get_cups_supported_pairs(&self) -> Vec<(&'static CStr, Cow<'static, CStr>)> {
+		// `job-sheets-supported` lists single sheets, so the start and end sheets are checked
+		// separately:
+		[Some(self.start), self.end]
+			.into_iter()
+			.flatten()
+			.map(|sheet| {
+				// SAFETY: `sheet` is displayed as a keyword, and thus does not contain 0 bytes.
+				let c_string = CString::new(sheet.to_string())
+					.expect("Could not convert job sheet to CString");
+				(opts::CUPS_JOB_SHEETS, Cow::Owned(c_string))
+			})
+			.collect()
+	}
+}
+
+impl CupsOption for JobAccountId {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_JOB_ACCOUNT_ID
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		// SAFETY: 0 bytes are removed from the string.
+		let c_string = CString::new(self.0.replace('\0', ""))
+			.expect("Could not convert job account ID to CString");
+		Cow::Owned(c_string)
+	}
+}
+
+impl CupsOption for JobAccountingUserId {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_JOB_ACCOUNTING_USER_ID
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		// SAFETY: 0 bytes are removed from the string.
+		let c_string = CString::new(self.0.replace('\0', ""))
+			.expect("Could not convert job accounting user ID to CString");
+		Cow::Owned(c_string)
+	}
+}
+
+impl CupsOption for Compression {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_COMPRESSION
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		Cow::Borrowed(match self {
+			Compression::None => opts::values::CUPS_COMPRESSION_NONE,
+			Compression::Gzip => opts::values::CUPS_COMPRESSION_GZIP,
+		})
+	}
+}
+
+impl CupsOption for JobPassword {
+	fn get_cups_option_name() -> &'static CStr {
+		opts::CUPS_JOB_PASSWORD
+	}
+	fn get_cups_option_value(&self) -> Cow<'static, CStr> {
+		// SAFETY: 0 bytes are removed from the string.
+		let c_string = CString::new(self.expose().replace('\0', ""))
+			.expect("Could not convert job password to CString");
+		Cow::Owned(c_string)
+	}
+	fn get_cups_supported_pairs(&self) -> Vec<(&'static CStr, Cow<'static, CStr>)> {
+		// `job-password-supported` is the maximum length of the password, so we check whether
+		// unencrypted passwords are supported instead:
+		vec![(
+			opts::CUPS_JOB_PASSWORD_ENCRYPTION,
+			Cow::Borrowed(opts::values::CUPS_JOB_PASSWORD_ENCRYPTION_NONE),
+		)]
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use std::ffi::CString;
-	use std::ops::Deref;
+	use std::ops::{Deref, RangeInclusive};
+
+	use strum::IntoEnumIterator;
 
-	use crate::options::Finishing;
+	use crate::options::{
+		Collation, ColorMode, Compression, CopiesInt, Finishing, FinishingTemplate, FromIpp,
+		JobPassword, JobRetention, MediaSize, MediaType, MirrorBool, NumberUpLayout, Orientation,
+		OutputOrder, PageBorder, PrintOptions, PrintScaling, Quality, RenderingIntent, SidesMode,
+	};
 	use crate::print::unix::cups::consts::opts;
-	use crate::print::unix::options::CupsOption;
+	use crate::print::unix::options::{CupsOption, replace_cups_option, set_cups_option};
 
 	#[test]
 	fn if_empty_finishings_then_cups_finishings_none() {
@@ -365,4 +1141,354 @@ mod tests {
 				.expect("Can't convert CUPS option value to String")
 		)
 	}
+
+	#[test]
+	fn if_many_page_ranges_then_comma_separated_ranges() {
+		// Several page ranges, including a single page:
+		let page_ranges = vec![1..=3, 5..=5, 8..=10];
+
+		// The CUPS option value should be a comma-separated list of ranges:
+		let value = page_ranges.get_cups_option_value();
+		assert_eq!(
+			c"1-3,5-5,8-10",
+			value.deref(),
+			// message:
+			"Page ranges should have value '1-3,5-5,8-10', was: '{}'",
+			value
+				.to_str()
+				.expect("Can't convert CUPS option value to String")
+		)
+	}
+
+	#[test]
+	fn if_page_ranges_start_at_zero_or_are_reversed_then_not_converted() {
+		// A range starting at page 0, and a range ending before it starts:
+		let values = ["0-3", "1-2,5-3"];
+
+		// The values should not be converted to page ranges:
+		for value in values {
+			let page_ranges = Vec::<RangeInclusive<u32>>::from_ipp(value);
+			assert!(
+				page_ranges.is_none(),
+				// message:
+				"Page ranges '{value}' should not be converted, were: {page_ranges:?}"
+			);
+		}
+	}
+
+	#[test]
+	fn if_mirror_set_then_cups_true_constant() {
+		// Mirroring is enabled:
+		let mirror = MirrorBool(true);
+
+		// The CUPS option value should be CUPS_TRUE:
+		let value = mirror.get_cups_option_value();
+		assert_eq!(
+			opts::values::CUPS_TRUE,
+			value.deref(),
+			// message:
+			"Mirror should have value 'true', was: '{}'",
+			value
+				.to_str()
+				.expect("Can't convert CUPS option value to String")
+		)
+	}
+
+	#[test]
+	fn if_positional_finishing_then_pwg_enum_code() {
+		// A positional finishing:
+		let finishing = Finishing::StapleTopLeft;
+
+		// The CUPS option value should be the PWG 5100.1 enum code:
+		let value = finishing.get_cups_option_value();
+		assert_eq!(
+			c"20",
+			value.deref(),
+			// message:
+			"Staple top left should be sent as enum code 20, was: {value:?}"
+		);
+	}
+
+	#[test]
+	fn if_many_finishing_templates_then_comma_separated_collections() {
+		// Two templates, one of them from a standard finishing:
+		let templates = vec![
+			FinishingTemplate::from(Finishing::StapleTopLeft),
+			FinishingTemplate::from("punch-dual-left"),
+		];
+
+		// The CUPS option value should be a comma-separated list of collections:
+		let value = templates.get_cups_option_value();
+		assert_eq!(
+			c"{finishing-template=staple-top-left},{finishing-template=punch-dual-left}",
+			value.deref(),
+			// message:
+			"Each template should be sent as a collection, was: {value:?}"
+		);
+	}
+
+	#[test]
+	fn if_many_finishing_templates_then_each_is_validated_separately() {
+		// Two templates:
+		let templates = vec![
+			FinishingTemplate::from("staple-top-left"),
+			FinishingTemplate::from("punch-dual-left"),
+		];
+
+		// Each template should be checked on its own:
+		let pairs = templates.get_cups_supported_pairs();
+		let values = pairs
+			.iter()
+			.map(|(name, value)| (*name, value.deref()))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			vec![
+				(opts::CUPS_FINISHING_TEMPLATE, c"staple-top-left"),
+				(opts::CUPS_FINISHING_TEMPLATE, c"punch-dual-left"),
+			],
+			values,
+			// message:
+			"Each template should have its own supported pair, were: {values:?}"
+		);
+	}
+
+	#[test]
+	fn if_finishing_template_has_special_characters_then_it_is_quoted() {
+		// A vendor template containing a space, a comma and a brace:
+		let templates = vec![
+			FinishingTemplate::from("Booklet, Saddle {A}"),
+			FinishingTemplate::from("staple"),
+		];
+
+		// The template should be quoted, and should survive a round trip:
+		let value = templates.get_cups_option_value();
+		assert_eq!(
+			c"{finishing-template=\"Booklet, Saddle {A}\"},{finishing-template=staple}",
+			value.deref(),
+			// message:
+			"Template should be quoted, was: {value:?}"
+		);
+		let parsed = Vec::<FinishingTemplate>::from_ipp(
+			value
+				.to_str()
+				.expect("Can't convert CUPS option value to String"),
+		)
+		.map(|templates| templates.into_iter().map(|t| t.0).collect::<Vec<_>>());
+		assert_eq!(
+			Some(vec![
+				String::from("Booklet, Saddle {A}"),
+				String::from("staple")
+			]),
+			parsed,
+			// message:
+			"Templates should survive a round trip, were: {parsed:?}"
+		);
+	}
+
+	#[test]
+	fn if_cups_option_value_not_convertible_then_field_is_kept_and_value_is_extra() {
+		// Options with a media size, set to a size without a variant:
+		let mut options = PrintOptions {
+			media_size: Some(MediaSize::A4),
+			..Default::default()
+		};
+		set_cups_option(&mut options, "media", "iso_b5_176x250mm");
+
+		// The field should be unchanged, and the value should be kept in the extra options:
+		assert!(
+			matches!(options.media_size, Some(MediaSize::A4)),
+			// message:
+			"Media size should be unchanged, was: {:?}",
+			options.media_size
+		);
+		assert_eq!(
+			Some("iso_b5_176x250mm"),
+			options.extra.get("media").map(String::as_str),
+			// message:
+			"Media should be kept in the extra options, were: {:?}",
+			options.extra
+		);
+	}
+
+	#[test]
+	fn if_cups_option_value_not_convertible_then_replacing_it_clears_field() {
+		// Options with a media size, adjusted to a size without a variant (as by a conflict
+		// resolution):
+		let mut options = PrintOptions {
+			media_size: Some(MediaSize::A4),
+			..Default::default()
+		};
+		replace_cups_option(&mut options, "media", "iso_b5_176x250mm");
+
+		// The field should be cleared, and the value should be kept in the extra options:
+		assert!(
+			options.media_size.is_none(),
+			// message:
+			"Media size should be cleared, was: {:?}",
+			options.media_size
+		);
+		assert_eq!(
+			Some("iso_b5_176x250mm"),
+			options.extra.get("media").map(String::as_str),
+			// message:
+			"Media should be kept in the extra options, were: {:?}",
+			options.extra
+		);
+	}
+
+	#[test]
+	fn if_lp_style_options_are_parsed_then_known_options_are_fields_and_others_extra() {
+		// Known options, and a vendor option:
+		let options = PrintOptions::from_cups_options(
+			"media=A4 sides=two-sided-long-edge copies=2 page-ranges=1-3,5 HPEconoMode=True",
+		)
+		.expect("Options could not be parsed");
+
+		// Known options should be converted to fields, and the vendor option should be extra:
+		assert!(
+			matches!(options.media_size, Some(MediaSize::A4)),
+			// message:
+			"Media size should be A4, was: {:?}",
+			options.media_size
+		);
+		assert!(
+			matches!(options.sides_mode, Some(SidesMode::TwoSidedPortrait)),
+			// message:
+			"Sides mode should be two-sided portrait, was: {:?}",
+			options.sides_mode
+		);
+		assert_eq!(
+			options.copies.map(|copies| copies.0),
+			Some(2),
+			// message:
+			"Copies should be 2"
+		);
+		assert_eq!(
+			options.page_ranges,
+			Some(vec![1..=3, 5..=5]),
+			// message:
+			"Page ranges should be parsed, including the single page"
+		);
+		assert_eq!(
+			options.extra.get("HPEconoMode").map(String::as_str),
+			Some("True"),
+			// message:
+			"Vendor option should be kept in the extra options, were: {:?}",
+			options.extra
+		);
+	}
+
+	#[test]
+	fn if_classic_collate_option_is_parsed_then_collation_is_set() {
+		// The classic `lp` options for collation:
+		let collated =
+			PrintOptions::from_cups_options("collate=true").expect("Options could not be parsed");
+		let uncollated =
+			PrintOptions::from_cups_options("collate=false").expect("Options could not be parsed");
+
+		// The options should be converted to the collation field:
+		assert!(
+			matches!(collated.collate, Some(Collation::Collated)),
+			// message:
+			"Collation should be collated, was: {:?}",
+			collated.collate
+		);
+		assert!(
+			matches!(uncollated.collate, Some(Collation::Uncollated)),
+			// message:
+			"Collation should be uncollated, was: {:?}",
+			uncollated.collate
+		);
+		assert!(
+			uncollated.extra.is_empty(),
+			// message:
+			"Collate should not be kept in the extra options, were: {:?}",
+			uncollated.extra
+		);
+	}
+
+	#[test]
+	fn if_lp_style_options_are_formatted_then_they_survive_round_trip() {
+		// A known option, and a vendor option whose value contains a space:
+		let options = PrintOptions::from_cups_options("media=iso_a4_210x297mm Vendor='a b'")
+			.expect("Options could not be parsed");
+
+		// Formatting and parsing the options again should result in the same options:
+		let string = options.to_cups_options();
+		let parsed = PrintOptions::from_cups_options(&string).expect("Options could not be parsed");
+		assert!(
+			matches!(parsed.media_size, Some(MediaSize::A4)),
+			// message:
+			"Media size should survive the round trip, string was: '{string}'"
+		);
+		assert_eq!(
+			parsed.extra.get("Vendor").map(String::as_str),
+			Some("a b"),
+			// message:
+			"Vendor option should be quoted, string was: '{string}'"
+		);
+	}
+
+	#[test]
+	fn if_job_password_is_set_then_it_is_not_in_option_string() {
+		// Options with a password, set as a field and as an extra option:
+		let mut options = PrintOptions {
+			copies: Some(CopiesInt(2)),
+			job_password: Some(JobPassword::new("4711")),
+			..Default::default()
+		};
+		options
+			.extra
+			.insert(String::from("job-password"), String::from("0815"));
+
+		// The option string should contain the other options, but not the password:
+		let string = options.to_cups_options();
+		assert_eq!(
+			"copies=2", string,
+			// message:
+			"Option string should not contain the password, was: '{string}'"
+		);
+	}
+
+	#[test]
+	fn if_cups_option_values_are_converted_from_ipp_then_all_variants_match() {
+		// The CUPS option value of every variant of the enum options should be converted back to
+		// the same variant:
+		assert_variants_round_trip::<NumberUpLayout>();
+		assert_variants_round_trip::<PageBorder>();
+		assert_variants_round_trip::<PrintScaling>();
+		assert_variants_round_trip::<Collation>();
+		assert_variants_round_trip::<Finishing>();
+		assert_variants_round_trip::<MediaSize>();
+		assert_variants_round_trip::<MediaType>();
+		assert_variants_round_trip::<Orientation>();
+		assert_variants_round_trip::<ColorMode>();
+		assert_variants_round_trip::<Quality>();
+		assert_variants_round_trip::<RenderingIntent>();
+		assert_variants_round_trip::<SidesMode>();
+		assert_variants_round_trip::<OutputOrder>();
+		assert_variants_round_trip::<JobRetention>();
+		assert_variants_round_trip::<Compression>();
+	}
+
+	/// Asserts that the CUPS option value of each variant of `O` is converted back to the variant.
+	fn assert_variants_round_trip<O>()
+	where
+		O: CupsOption + IntoEnumIterator,
+	{
+		for variant in O::iter() {
+			let value = variant.get_cups_option_value();
+			let value = value
+				.to_str()
+				.expect("Can't convert CUPS option value to String");
+			let converted = O::from_ipp(value).map(|option| option.to_human_string());
+			assert_eq!(
+				Some(variant.to_human_string()),
+				converted,
+				// message:
+				"{} value '{value}' should be converted back to the same variant",
+				O::get_name()
+			);
+		}
+	}
 }
@@ -0,0 +1,51 @@
+use std::ffi::CStr;
+
+use crate::error::PrintError;
+use crate::print::Printer;
+use crate::print::unix::cups::{ipp_op_e, ipp_tag_e};
+use crate::print::unix::ipp::{IppRequest, get_job_uri, get_printer_uri};
+
+/// The resource job requests are sent to.
+const RESOURCE: &CStr = c"/jobs";
+
+/// Moves a job of the printer `from` to the printer `to`, using the CUPS-Move-Job operation.
+/// If `job_id` is `None`, all jobs of the printer `from` are moved.
+pub fn move_jobs(job_id: Option<i32>, from: &Printer, to: &Printer) -> Result<(), PrintError> {
+	let mut request = IppRequest::new(ipp_op_e::IPP_OP_CUPS_MOVE_JOB);
+	request.add_string(
+		ipp_tag_e::IPP_TAG_OPERATION,
+		ipp_tag_e::IPP_TAG_URI,
+		c"printer-uri",
+		&get_printer_uri(from)?,
+	);
+	if let Some(job_id) = job_id {
+		request.add_integer(
+			ipp_tag_e::IPP_TAG_OPERATION,
+			ipp_tag_e::IPP_TAG_INTEGER,
+			c"job-id",
+			job_id,
+		);
+	}
+	request.add_string(
+		ipp_tag_e::IPP_TAG_JOB,
+		ipp_tag_e::IPP_TAG_URI,
+		c"job-printer-uri",
+		&get_printer_uri(to)?,
+	);
+	request.send(RESOURCE)?;
+	Ok(())
+}
+
+/// Restarts a job, using the Restart-Job operation.
+pub fn restart_job(job_id: i32) -> Result<(), PrintError> {
+	let uri = get_job_uri(job_id)?;
+	let mut request = IppRequest::new(ipp_op_e::IPP_OP_RESTART_JOB);
+	request.add_string(
+		ipp_tag_e::IPP_TAG_OPERATION,
+		ipp_tag_e::IPP_TAG_URI,
+		c"job-uri",
+		&uri,
+	);
+	request.send(RESOURCE)?;
+	Ok(())
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+
+use crate::error::PrintError;
+use crate::events::{Event, EventKind};
+use crate::print::Printer;
+use crate::print::unix::cups::{ipp_op_e, ipp_tag_e};
+use crate::print::unix::ipp::{IppRequest, get_printer_uri, get_server_uri};
+
+/// The resource subscription requests are sent to.
+const RESOURCE: &CStr = c"/";
+
+/// Creates a pull subscription (polled via Get-Notifications) for the specified events.
+/// Returns the ID of the subscription.
+pub fn create_subscription(
+	printer: Option<&Printer>,
+	events: &[EventKind],
+) -> Result<i32, PrintError> {
+	let uri = match printer {
+		Some(printer) => get_printer_uri(printer)?,
+		None => get_server_uri()?,
+	};
+	let mut events = events
+		.iter()
+		.map(|event| CString::new(event.to_string()))
+		.collect::<Result<Vec<_>, _>>()?;
+	if events.is_empty() {
+		events.push(c"all".to_owned());
+	}
+
+	let mut request = IppRequest::new(ipp_op_e::IPP_OP_CREATE_PRINTER_SUBSCRIPTIONS);
+	request.add_string(
+		ipp_tag_e::IPP_TAG_OPERATION,
+		ipp_tag_e::IPP_TAG_URI,
+		c"printer-uri",
+		&uri,
+	);
+	request.add_strings(
+		ipp_tag_e::IPP_TAG_SUBSCRIPTION,
+		ipp_tag_e::IPP_TAG_KEYWORD,
+		c"notify-events",
+		&events,
+	);
+	request.add_string(
+		ipp_tag_e::IPP_TAG_SUBSCRIPTION,
+		ipp_tag_e::IPP_TAG_KEYWORD,
+		c"notify-pull-method",
+		c"ippget",
+	);
+
+	request
+		.send(RESOURCE)?
+		.find_value("notify-subscription-id")
+		.and_then(|id| id.parse().ok())
+		.ok_or(PrintError::NecessaryInformationMissing(String::from(
+			"no subscription ID",
+		)))
+}
+
+/// Retrieves the events of a subscription, starting at the sequence number `first`.
+/// Does not wait for new events.
+pub fn get_notifications(subscription: i32, first: i32) -> Result<Vec<Event>, PrintError> {
+	let mut request = IppRequest::new(ipp_op_e::IPP_OP_GET_NOTIFICATIONS);
+	request.add_string(
+		ipp_tag_e::IPP_TAG_OPERATION,
+		ipp_tag_e::IPP_TAG_URI,
+		c"printer-uri",
+		&get_server_uri()?,
+	);
+	request.add_integer(
+		ipp_tag_e::IPP_TAG_OPERATION,
+		ipp_tag_e::IPP_TAG_INTEGER,
+		c"notify-subscription-ids",
+		subscription,
+	);
+	request.add_integer(
+		ipp_tag_e::IPP_TAG_OPERATION,
+		ipp_tag_e::IPP_TAG_INTEGER,
+		c"notify-sequence-numbers",
+		first,
+	);
+	request.add_boolean(ipp_tag_e::IPP_TAG_OPERATION, c"notify-wait", false);
+	let mut response = request.send(RESOURCE)?;
+
+	// Each event is a separate group of event notification attributes:
+	let mut groups = Vec::new();
+	let mut current = HashMap::<String, Vec<String>>::new();
+	for attribute in response.attributes() {
+		let name = attribute
+			.get_name()
+			.filter(|_| attribute.get_group() == ipp_tag_e::IPP_TAG_EVENT_NOTIFICATION);
+		match name {
+			Some(name) => {
+				current.insert(name, attribute.values());
+			}
+			None if !current.is_empty() => groups.push(std::mem::take(&mut current)),
+			None => {}
+		}
+	}
+	if !current.is_empty() {
+		groups.push(current);
+	}
+	Ok(groups.iter().filter_map(Event::from_attributes).collect())
+}
+
+/// Cancels a subscription.
+pub fn cancel_subscription(subscription: i32) -> Result<(), PrintError> {
+	let mut request = IppRequest::new(ipp_op_e::IPP_OP_CANCEL_SUBSCRIPTION);
+	request.add_string(
+		ipp_tag_e::IPP_TAG_OPERATION,
+		ipp_tag_e::IPP_TAG_URI,
+		c"printer-uri",
+		&get_server_uri()?,
+	);
+	request.add_integer(
+		ipp_tag_e::IPP_TAG_OPERATION,
+		ipp_tag_e::IPP_TAG_INTEGER,
+		c"notify-subscription-id",
+		subscription,
+	);
+	request.send(RESOURCE)?;
+	Ok(())
+}
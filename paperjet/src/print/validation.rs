@@ -0,0 +1,64 @@
+use crate::options::PrintOptions;
+
+/// The result of checking print options for conflicts with each other.
+#[derive(Clone, Debug)]
+pub enum ConflictResolution {
+	/// The options do not conflict, or all conflicts could be resolved.
+	/// Contains the (possibly adjusted) options. Adjusted values that can't be represented by
+	/// a field of [`PrintOptions`] are set in [`PrintOptions::extra`].
+	Resolved(Box<PrintOptions>),
+	/// At least one conflict could not be resolved automatically.
+	/// Contains all conflicts that were found.
+	Unresolved(Vec<OptionConflict>),
+}
+
+/// A conflict between an option and other options of a print job.
+#[derive(Clone, Debug)]
+pub struct OptionConflict {
+	/// The name and value of the option that was checked.
+	pub option: (String, String),
+	/// The names and values of the options that conflict with [`Self::option`].
+	pub conflicting: Vec<(String, String)>,
+	/// The names and values of the options that resolve the conflict.
+	/// Empty if no resolution could be found.
+	pub resolution: Vec<(String, String)>,
+}
+
+/// A report of all options that a printer does not support.
+#[derive(Clone, Debug, Default)]
+pub struct ValidationReport {
+	/// The options that are not supported, in the order they were checked.
+	pub unsupported: Vec<UnsupportedOption>,
+}
+impl ValidationReport {
+	/// Returns `true` if all options are supported, and `false` otherwise.
+	pub fn is_valid(&self) -> bool {
+		self.unsupported.is_empty()
+	}
+}
+
+/// An option (or an option value) that is not supported by a printer.
+#[derive(Clone, Debug)]
+pub struct UnsupportedOption {
+	/// The name of the option.
+	pub name: String,
+	/// The value of the option.
+	pub value: String,
+	/// The values of this option that the printer supports, as reported by the printer.
+	/// Empty if the printer does not support the option at all, or reports no values.
+	pub supported: Vec<String>,
+	/// The reason why the value is invalid regardless of the printer (such as a reversed page
+	/// range), or `None` if it is only unsupported by the printer.
+	pub reason: Option<String>,
+}
+
+/// A change made to an unsupported option, according to [`crate::options::OptionPolicy`].
+#[derive(Clone, Debug)]
+pub struct OptionSubstitution {
+	/// The name of the option.
+	pub name: String,
+	/// The value of the option that was requested, but is not supported.
+	pub requested: String,
+	/// The value that was used instead, or `None` if the option was removed.
+	pub substitute: Option<String>,
+}
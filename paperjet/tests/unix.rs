@@ -2,7 +2,13 @@
 
 mod utils;
 
-use paperjet::options::PrintOptions;
+use paperjet::events::EventKind;
+use paperjet::options::{
+	Compression, CopiesInt, DocumentOptions, JobPriorityInt, JobRetention, OptionPolicy,
+	PrintOptions,
+};
+use paperjet::raw::Zpl;
+use paperjet::validation::ConflictResolution;
 
 use crate::utils::unixutils::FakePrinter;
 
@@ -101,6 +107,134 @@ fn if_printer_accepts_jobs_then_print_returns_unit() {
 	);
 }
 
+#[test]
+fn if_printer_accepts_jobs_then_print_with_compression_returns_job() {
+	// Create fake printer that accepts jobs:
+	let fake = FakePrinter::try_new(true).expect("Could not create a fake printer");
+	// Create a mock document:
+	let document = [0u8; 1024];
+	// Request compression, falling back to no compression if it's unsupported:
+	let options = PrintOptions {
+		compression: Some(Compression::Gzip),
+		policy: Some(OptionPolicy::NearestSupported),
+		..Default::default()
+	};
+
+	// Get the printer:
+	let printer = paperjet::get_printer(&fake.name).expect("Could not find the fake printer");
+	// Submit print job:
+	let result = paperjet::print([&document[..]], printer, options);
+	assert!(
+		result.is_ok(),
+		"Print job should be submitted successfully, but failed: {result:?}"
+	);
+}
+
+#[test]
+fn if_printer_accepts_jobs_then_print_with_extra_options_returns_job() {
+	// Create fake printer that accepts jobs:
+	let fake = FakePrinter::try_new(true).expect("Could not create a fake printer");
+	// Create a mock document:
+	let document = [0u8; 1024];
+	// Set a vendor option, which the printer does not advertise:
+	let mut options = PrintOptions::default();
+	options
+		.extra
+		.insert(String::from("HPEconoMode"), String::from("True"));
+
+	// Get the printer:
+	let printer = paperjet::get_printer(&fake.name).expect("Could not find the fake printer");
+	// Submit print job:
+	let result = paperjet::print([&document[..]], printer, options);
+	assert!(
+		result.is_ok(),
+		"Print job should be submitted successfully, but failed: {result:?}"
+	);
+}
+
+#[test]
+fn if_printer_does_not_advertise_retention_then_print_reports_it_as_dropped() {
+	// Create fake printer that accepts jobs:
+	let fake = FakePrinter::try_new(true).expect("Could not create a fake printer");
+	// Create a mock document:
+	let document = [0u8; 1024];
+	// Request retention, which CUPS does not support:
+	let options = PrintOptions {
+		retention: Some(JobRetention::Indefinite),
+		policy: Some(OptionPolicy::DropUnsupported),
+		..Default::default()
+	};
+
+	// Get the printer:
+	let printer = paperjet::get_printer(&fake.name).expect("Could not find the fake printer");
+	// Submit print job:
+	let job = paperjet::print([&document[..]], printer, options)
+		.expect("Print job should be submitted successfully, but failed");
+	// Retention should be reported as removed:
+	assert!(
+		job.substitutions
+			.iter()
+			.any(|substitution| substitution.name == "retention"
+				&& substitution.substitute.is_none()),
+		"Retention should be reported as dropped, but the substitutions were: {:?}",
+		job.substitutions
+	);
+}
+
+#[test]
+fn if_extra_option_is_set_by_field_then_print_reports_substitution() {
+	// Create fake printer that accepts jobs:
+	let fake = FakePrinter::try_new(true).expect("Could not create a fake printer");
+	// Create a mock document:
+	let document = [0u8; 1024];
+	// Set the amount of copies both as a field and as an extra option:
+	let mut options = PrintOptions {
+		copies: Some(CopiesInt(2)),
+		..Default::default()
+	};
+	options
+		.extra
+		.insert(String::from("copies"), String::from("5"));
+
+	// Get the printer:
+	let printer = paperjet::get_printer(&fake.name).expect("Could not find the fake printer");
+	// Submit print job:
+	let job = paperjet::print([&document[..]], printer, options)
+		.expect("Print job should be submitted successfully, but failed");
+	// The field should take precedence over the extra option:
+	let substitution = job
+		.substitutions
+		.iter()
+		.find(|substitution| substitution.name == "copies")
+		.expect("The extra option should be reported as substituted, but wasn't");
+	assert_eq!(
+		substitution.requested, "5",
+		"The requested value should be the extra option's value, but wasn't"
+	);
+	assert_eq!(
+		substitution.substitute.as_deref(),
+		Some("2"),
+		"The substitute should be the field's value, but wasn't"
+	);
+}
+
+#[test]
+fn if_printer_accepts_jobs_then_print_raw_returns_job() {
+	// Create fake printer that accepts jobs:
+	let fake = FakePrinter::try_new(true).expect("Could not create a fake printer");
+	// Create a label:
+	let label = Zpl::new().text(10, 10, 30, "paperjet").cut().build();
+
+	// Get the printer:
+	let printer = paperjet::get_printer(&fake.name).expect("Could not find the fake printer");
+	// Submit raw print job:
+	let result = paperjet::print_raw([&label[..]], printer);
+	assert!(
+		result.is_ok(),
+		"Raw print job should be submitted successfully, but wasn't"
+	);
+}
+
 #[test]
 fn if_printer_not_accepts_jobs_then_print_returns_err() {
 	// Create fake printer that doesn't accept jobs:
@@ -130,3 +264,208 @@ fn if_printer_no_longer_exists_then_print_returns_err() {
 	let result = paperjet::print([&document[..]], printer, PrintOptions::default());
 	assert!(result.is_err(), "Print job should not be accepted, but was");
 }
+
+#[test]
+fn if_no_options_then_validate_returns_valid_report() {
+	// Create a fake printer:
+	let fake = FakePrinter::try_new(false).expect("Could not create a fake printer");
+	// Get the printer:
+	let printer = paperjet::get_printer(&fake.name).expect("Could not find the fake printer");
+
+	// Validate empty options:
+	let report = paperjet::validate(&printer, &PrintOptions::default())
+		.expect("Validation should succeed for an existing printer");
+	assert!(
+		report.is_valid(),
+		"Empty options should be valid, but unsupported options were reported: {:?}",
+		report.unsupported
+	);
+}
+
+#[test]
+fn if_values_are_invalid_then_validate_reports_all_of_them() {
+	// Create a fake printer:
+	let fake = FakePrinter::try_new(false).expect("Could not create a fake printer");
+	// Get the printer:
+	let printer = paperjet::get_printer(&fake.name).expect("Could not find the fake printer");
+	// Options with an empty page range list and an out-of-range priority:
+	let options = PrintOptions {
+		page_ranges: Some(Vec::new()),
+		job_priority: Some(JobPriorityInt(0)),
+		..Default::default()
+	};
+
+	// Validate:
+	let report = paperjet::validate(&printer, &options)
+		.expect("Validation should report invalid values, but failed");
+	let invalid = report
+		.unsupported
+		.iter()
+		.filter(|option| option.reason.is_some())
+		.count();
+	assert_eq!(
+		invalid, 2,
+		"Both invalid values should be reported, but the report was: {:?}",
+		report.unsupported
+	);
+}
+
+#[test]
+fn if_printer_no_longer_exists_then_validate_returns_err() {
+	// Create a fake printer:
+	let fake = FakePrinter::try_new(false).expect("Could not create a fake printer");
+	// Get the printer:
+	let printer = paperjet::get_printer(&fake.name).expect("Could not find the fake printer");
+	// Remove printer:
+	drop(fake);
+
+	// Validate:
+	let result = paperjet::validate(&printer, &PrintOptions::default());
+	assert!(result.is_err(), "Validation should fail, but didn't");
+}
+
+#[test]
+fn if_options_do_not_conflict_then_resolve_conflicts_returns_resolved_options() {
+	// Create a fake printer:
+	let fake = FakePrinter::try_new(false).expect("Could not create a fake printer");
+	// Get the printer:
+	let printer = paperjet::get_printer(&fake.name).expect("Could not find the fake printer");
+	// A single option can't conflict with others:
+	let options = PrintOptions {
+		copies: Some(CopiesInt(2)),
+		..Default::default()
+	};
+
+	// Resolve conflicts:
+	let resolution = paperjet::resolve_conflicts(&printer, &options)
+		.expect("Resolving conflicts should succeed for an existing printer");
+	assert!(
+		matches!(
+			resolution,
+			ConflictResolution::Resolved(ref resolved) if resolved.copies.map(|c| c.0) == Some(2)
+		),
+		"Options should be resolved unchanged, but were: {resolution:?}"
+	);
+}
+
+#[test]
+fn if_printer_exists_then_subscribe_returns_subscription() {
+	// Create a fake printer:
+	let fake = FakePrinter::try_new(false).expect("Could not create a fake printer");
+	// Get the printer:
+	let printer = paperjet::get_printer(&fake.name).expect("Could not find the fake printer");
+
+	// Subscribe to state changes, and poll once:
+	let mut subscription = paperjet::subscribe(Some(&printer), &[EventKind::PrinterStateChanged])
+		.expect("Subscription should succeed for an existing printer");
+	let result = subscription.poll();
+	assert!(
+		result.is_ok(),
+		"Polling should succeed, but failed: {result:?}"
+	);
+}
+
+#[test]
+fn if_printer_exists_then_get_capabilities_returns_capabilities() {
+	// Create a fake printer:
+	let fake = FakePrinter::try_new(false).expect("Could not create a fake printer");
+	// Get the printer:
+	let printer = paperjet::get_printer(&fake.name).expect("Could not find the fake printer");
+
+	// Retrieve the capabilities:
+	let result = paperjet::get_capabilities(&printer);
+	assert!(
+		result.is_ok(),
+		"Capabilities should be retrieved for an existing printer, but failed: {result:?}"
+	);
+}
+
+#[test]
+fn if_printers_exist_then_move_all_jobs_returns_unit() {
+	// Create two fake printers:
+	let fake_from = FakePrinter::try_new(true).expect("Could not create a fake printer");
+	let fake_to = FakePrinter::try_new(true).expect("Could not create a fake printer");
+	// Get the printers:
+	let from = paperjet::get_printer(&fake_from.name).expect("Could not find the fake printer");
+	let to = paperjet::get_printer(&fake_to.name).expect("Could not find the fake printer");
+
+	// Move all jobs (there may be none):
+	let result = paperjet::move_all_jobs(&from, &to);
+	assert!(
+		result.is_ok(),
+		"Jobs should be moved, but weren't: {result:?}"
+	);
+}
+
+#[test]
+fn if_job_not_exists_then_move_job_returns_err() {
+	// Create two fake printers:
+	let fake_from = FakePrinter::try_new(true).expect("Could not create a fake printer");
+	let fake_to = FakePrinter::try_new(true).expect("Could not create a fake printer");
+	// Get the printers:
+	let from = paperjet::get_printer(&fake_from.name).expect("Could not find the fake printer");
+	let to = paperjet::get_printer(&fake_to.name).expect("Could not find the fake printer");
+
+	// Move a job that doesn't exist:
+	let result = paperjet::move_job(i32::MAX, &from, &to);
+	assert!(result.is_err(), "Moving should fail, but didn't");
+}
+
+#[test]
+fn if_job_not_exists_then_restart_job_returns_err() {
+	// Restart a job that doesn't exist:
+	let result = paperjet::restart_job(i32::MAX);
+	assert!(result.is_err(), "Restarting should fail, but didn't");
+}
+
+#[test]
+fn if_printer_accepts_jobs_then_print_documents_returns_job() {
+	// Create fake printer that accepts jobs:
+	let fake = FakePrinter::try_new(true).expect("Could not create a fake printer");
+	// Create mock documents:
+	let cover = [0u8; 1024];
+	let enclosure = [0u8; 1024];
+
+	// Get the printer:
+	let printer = paperjet::get_printer(&fake.name).expect("Could not find the fake printer");
+	// Submit print job with two documents:
+	let documents = [
+		(&cover[..], DocumentOptions::default()),
+		(&enclosure[..], DocumentOptions::default()),
+	];
+	let result = paperjet::print_documents(documents, printer, PrintOptions::default());
+	assert!(
+		result.is_ok(),
+		"Print job should be submitted successfully, but wasn't"
+	);
+}
+
+#[test]
+fn if_documents_have_different_options_then_print_documents_returns_job() {
+	// Create fake printer that accepts jobs:
+	let fake = FakePrinter::try_new(true).expect("Could not create a fake printer");
+	// Create mock documents:
+	let cover = [0u8; 1024];
+	let enclosure = [0u8; 1024];
+	// Print two copies of the enclosure only:
+	let enclosure_options = DocumentOptions {
+		copies: Some(CopiesInt(2)),
+		..Default::default()
+	};
+
+	// Get the printer:
+	let printer = paperjet::get_printer(&fake.name).expect("Could not find the fake printer");
+	// Submit print job with two documents:
+	let documents = [
+		(&cover[..], DocumentOptions::default()),
+		(&enclosure[..], enclosure_options),
+	];
+	let result = paperjet::print_documents(documents, printer, PrintOptions::default());
+	let job = result.expect("Print job should be submitted successfully, but failed");
+	// CUPS supports multiple-document-handling, so the options of the documents are kept:
+	assert!(
+		job.substitutions.is_empty(),
+		"Options should not be substituted, but were: {:?}",
+		job.substitutions
+	);
+}